@@ -0,0 +1,206 @@
+use std::{convert::Infallible, fmt, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+// whatever a level returns, serialized as a number or a string
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i64),
+    // doesn't fit in an i64
+    Big(i128),
+    Text(String),
+}
+
+impl Answer {
+    // "+05 " and 5 are the same answer, text is only trimmed
+    pub fn parse(s: &str) -> Self {
+        let s = s.trim();
+
+        if let Ok(n) = s.parse::<i64>() {
+            Self::Int(n)
+        } else if let Ok(n) = s.parse::<i128>() {
+            Self::Big(n)
+        } else {
+            Self::Text(s.to_string())
+        }
+    }
+
+    // a level that formats its number as text still compares equal to the number
+    pub fn normalize(&self) -> Self {
+        match self {
+            Self::Text(s) => Self::parse(s),
+            Self::Big(n) => i64::try_from(*n).map_or(Self::Big(*n), Self::Int),
+            Self::Int(n) => Self::Int(*n),
+        }
+    }
+
+    // compares against a stored answer
+    pub fn matches(&self, expected: &str) -> bool {
+        self.normalize() == Self::parse(expected)
+    }
+
+    pub fn as_int(&self) -> Option<i128> {
+        match self.normalize() {
+            Self::Int(n) => Some(n as i128),
+            Self::Big(n) => Some(n),
+            Self::Text(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(n) => write!(f, "{n}"),
+            Self::Big(n) => write!(f, "{n}"),
+            Self::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+// neither json nor toml can hold an i128, so a big answer goes as its digits
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Int(n) => serializer.serialize_i64(*n),
+            Self::Big(n) => serializer.collect_str(n),
+            Self::Text(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+struct Visitor;
+
+impl de::Visitor<'_> for Visitor {
+    type Value = Answer;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an integer or a string")
+    }
+
+    fn visit_i64<E: de::Error>(self, n: i64) -> Result<Answer, E> {
+        Ok(Answer::Int(n))
+    }
+
+    fn visit_u64<E: de::Error>(self, n: u64) -> Result<Answer, E> {
+        Ok(Answer::from(n))
+    }
+
+    fn visit_i128<E: de::Error>(self, n: i128) -> Result<Answer, E> {
+        Ok(Answer::from(n))
+    }
+
+    fn visit_u128<E: de::Error>(self, n: u128) -> Result<Answer, E> {
+        Ok(Answer::from(n))
+    }
+
+    // only digits too big for an i64 come back as a number, "12" stays text
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Answer, E> {
+        match s.parse::<i128>() {
+            Ok(n) if i64::try_from(n).is_err() && n.to_string() == s => Ok(Answer::Big(n)),
+            _ => Ok(Answer::Text(s.to_string())),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(s))
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    match i64::try_from(n) {
+                        Ok(n) => Self::Int(n),
+                        Err(_) => match i128::try_from(n) {
+                            Ok(n) => Self::Big(n),
+                            Err(_) => Self::Text(n.to_string()),
+                        },
+                    }
+                }
+            }
+        )*
+    };
+}
+
+from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers() -> Vec<Answer> {
+        vec![
+            Answer::Int(0),
+            Answer::Int(-42),
+            Answer::Int(i64::MAX),
+            Answer::Big(i64::MAX as i128 + 1),
+            Answer::Big(i64::MIN as i128 - 1),
+            Answer::Big(i128::MAX),
+            Answer::Text("abc".to_string()),
+            Answer::Text("12".to_string()),
+            Answer::Text("1,2,3".to_string()),
+        ]
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper {
+        answer: Answer,
+    }
+
+    #[test]
+    fn json_round_trip() {
+        for answer in answers() {
+            let json = serde_json::to_string(&answer).unwrap();
+            assert_eq!(serde_json::from_str::<Answer>(&json).unwrap(), answer);
+        }
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        for answer in answers() {
+            let wrapper = Wrapper { answer };
+            let toml = toml::to_string(&wrapper).unwrap();
+            assert_eq!(toml::from_str::<Wrapper>(&toml).unwrap(), wrapper);
+        }
+    }
+
+    #[test]
+    fn json_shape() {
+        assert_eq!(serde_json::to_string(&Answer::Int(5)).unwrap(), "5");
+        assert_eq!(
+            serde_json::to_string(&Answer::Big(1 << 70)).unwrap(),
+            "\"1180591620717411303424\""
+        );
+        assert_eq!(serde_json::to_string(&Answer::from("x")).unwrap(), "\"x\"");
+        // a number too big for an i64 in someone else's json
+        assert_eq!(
+            serde_json::from_str::<Answer>("18446744073709551615").unwrap(),
+            Answer::Big(u64::MAX as i128)
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use crate::grid::{Grid, Point};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    size: Vec<usize>,
+    sets: usize,
+}
+
+impl DisjointSet {
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+            size: vec![1; n],
+            sets: n,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    pub fn num_sets(&self) -> usize {
+        self.sets
+    }
+
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // path compression, second pass points everything straight at the root
+        let mut x = x;
+
+        while self.parent[x] != root {
+            let next = self.parent[x];
+            self.parent[x] = root;
+            x = next;
+        }
+
+        root
+    }
+
+    // false if a and b were already in the same set
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+
+        if a == b {
+            return false;
+        }
+
+        // union by rank
+        let (hi, lo) = if self.rank[a] < self.rank[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+
+        self.parent[lo] = hi;
+        self.size[hi] += self.size[lo];
+
+        if self.rank[hi] == self.rank[lo] {
+            self.rank[hi] += 1;
+        }
+
+        self.sets -= 1;
+
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    pub fn set_size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+}
+
+// same thing, but keyed by grid points
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GridSets {
+    inner: DisjointSet,
+    height: usize,
+    width: usize,
+}
+
+impl GridSets {
+    pub fn new(height: usize, width: usize) -> Self {
+        Self {
+            inner: DisjointSet::new(height * width),
+            height,
+            width,
+        }
+    }
+
+    pub fn for_grid<T>(grid: &Grid<T>) -> Self {
+        Self::new(grid.height(), grid.width())
+    }
+
+    pub fn index(&self, p: Point) -> usize {
+        let (y, x) = p
+            .as_usize_lim((self.height, self.width))
+            .expect("Point out of bounds");
+
+        y * self.width + x
+    }
+
+    pub fn point(&self, i: usize) -> Point {
+        Point::from_1d(i, self.width)
+    }
+
+    pub fn num_sets(&self) -> usize {
+        self.inner.num_sets()
+    }
+
+    pub fn find(&mut self, p: Point) -> Point {
+        let root = self.inner.find(self.index(p));
+        self.point(root)
+    }
+
+    pub fn union(&mut self, a: Point, b: Point) -> bool {
+        self.inner.union(self.index(a), self.index(b))
+    }
+
+    pub fn connected(&mut self, a: Point, b: Point) -> bool {
+        self.inner.connected(self.index(a), self.index(b))
+    }
+
+    pub fn set_size(&mut self, p: Point) -> usize {
+        self.inner.set_size(self.index(p))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Direction;
+
+    #[test]
+    fn union_find() {
+        let mut sets = DisjointSet::new(6);
+
+        assert_eq!(sets.len(), 6);
+        assert_eq!(sets.num_sets(), 6);
+        assert!(!sets.connected(0, 1));
+
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        // already joined through 1 and 3
+        assert!(!sets.union(0, 2));
+
+        assert_eq!(sets.num_sets(), 3);
+        assert!(sets.connected(0, 2));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(sets.find(0), sets.find(3));
+        assert_ne!(sets.find(4), sets.find(5));
+    }
+
+    #[test]
+    fn set_sizes() {
+        let mut sets = DisjointSet::new(5);
+
+        assert_eq!(sets.set_size(0), 1);
+
+        sets.union(0, 1);
+        sets.union(1, 2);
+        sets.union(2, 0);
+
+        assert_eq!(sets.set_size(0), 3);
+        assert_eq!(sets.set_size(2), 3);
+        assert_eq!(sets.set_size(3), 1);
+
+        sets.union(3, 4);
+        sets.union(4, 0);
+
+        assert_eq!(sets.set_size(1), 5);
+        assert_eq!(sets.num_sets(), 1);
+    }
+
+    #[test]
+    fn empty() {
+        let sets = DisjointSet::new(0);
+
+        assert!(sets.is_empty());
+        assert_eq!(sets.num_sets(), 0);
+    }
+
+    #[test]
+    fn grid_regions() {
+        let plots = "AAB\
+                     ABB\
+                     CCB";
+        let grid = Grid::new_from(plots.chars().collect(), 3);
+        let mut sets = GridSets::for_grid(&grid);
+
+        for (p, c) in grid.iter() {
+            for dir in Direction::ALL {
+                let q = p + *dir;
+
+                if grid.is(q, c) {
+                    sets.union(p, q);
+                }
+            }
+        }
+
+        assert_eq!(sets.num_sets(), 3);
+        assert_eq!(sets.set_size(Point::new(0, 0)), 3);
+        assert_eq!(sets.set_size(Point::new(0, 2)), 4);
+        assert_eq!(sets.set_size(Point::new(2, 1)), 2);
+        assert!(sets.connected(Point::new(0, 2), Point::new(2, 2)));
+        assert!(!sets.connected(Point::new(1, 0), Point::new(2, 0)));
+        assert_eq!(sets.find(Point::new(1, 1)), sets.find(Point::new(0, 2)));
+    }
+
+    #[test]
+    fn grid_index() {
+        let sets = GridSets::new(2, 3);
+
+        for i in 0..6 {
+            assert_eq!(sets.index(sets.point(i)), i);
+        }
+
+        assert_eq!(sets.index(Point::new(1, 2)), 5);
+    }
+}
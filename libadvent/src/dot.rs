@@ -0,0 +1,194 @@
+use std::{
+    collections::HashSet,
+    fmt, fs,
+    io::{self, Write},
+    path::Path,
+    process::{Command, Stdio},
+};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Dot {
+    directed: bool,
+    nodes: Vec<(String, Option<String>)>,
+    edges: Vec<(String, String, Option<String>)>,
+    highlight: HashSet<String>,
+}
+
+impl Dot {
+    pub fn new(directed: bool) -> Self {
+        Self {
+            directed,
+            ..Self::default()
+        }
+    }
+
+    pub fn node(&mut self, id: impl ToString, label: Option<String>) {
+        self.nodes.push((id.to_string(), label));
+    }
+
+    pub fn edge(&mut self, a: impl ToString, b: impl ToString, label: Option<String>) {
+        self.edges.push((a.to_string(), b.to_string(), label));
+    }
+
+    // highlighted nodes are filled red, along with every edge leaving them
+    pub fn highlight(&mut self, ids: impl IntoIterator<Item = impl ToString>) {
+        self.highlight
+            .extend(ids.into_iter().map(|id| id.to_string()));
+    }
+
+    pub fn write(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    // needs graphviz installed, the output format comes from the extension (e.g. out.svg)
+    pub fn render(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let format = path.extension().and_then(|e| e.to_str()).unwrap_or("svg");
+
+        let mut dot = Command::new("dot")
+            .arg(format!("-T{format}"))
+            .arg("-o")
+            .arg(path)
+            .stdin(Stdio::piped())
+            .spawn()
+            .map_err(|e| match e.kind() {
+                io::ErrorKind::NotFound => {
+                    io::Error::new(e.kind(), "dot not found, is graphviz installed?")
+                }
+                _ => e,
+            })?;
+
+        dot.stdin
+            .take()
+            .unwrap()
+            .write_all(self.to_string().as_bytes())?;
+
+        let status = dot.wait()?;
+
+        if !status.success() {
+            return Err(io::Error::other(format!("dot exited with {status}")));
+        }
+
+        Ok(())
+    }
+}
+
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+impl fmt::Display for Dot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (kind, arrow) = if self.directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+
+        writeln!(f, "{kind} {{")?;
+
+        for (id, label) in &self.nodes {
+            let mut attrs = vec![];
+
+            if let Some(label) = label {
+                attrs.push(format!("label={}", quote(label)));
+            }
+
+            if self.highlight.contains(id) {
+                attrs.push("style=filled fillcolor=red".to_string());
+            }
+
+            writeln!(f, "    {} [{}];", quote(id), attrs.join(" "))?;
+        }
+
+        for (a, b, label) in &self.edges {
+            let mut attrs = vec![];
+
+            if let Some(label) = label {
+                attrs.push(format!("label={}", quote(label)));
+            }
+
+            if self.highlight.contains(a) {
+                attrs.push("color=red".to_string());
+            }
+
+            writeln!(
+                f,
+                "    {} {arrow} {} [{}];",
+                quote(a),
+                quote(b),
+                attrs.join(" ")
+            )?;
+        }
+
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Graph;
+
+    #[test]
+    fn directed() {
+        let mut dot = Dot::new(true);
+        dot.node("x00", Some("x00 = 1".to_string()));
+        dot.node("z00 gate", Some("Xor".to_string()));
+        dot.node("z00", None);
+        dot.edge("x00", "z00 gate", None);
+        dot.edge("z00 gate", "z00", Some("out".to_string()));
+        dot.highlight(["z00 gate"]);
+
+        assert_eq!(
+            dot.to_string(),
+            "\
+digraph {
+    \"x00\" [label=\"x00 = 1\"];
+    \"z00 gate\" [label=\"Xor\" style=filled fillcolor=red];
+    \"z00\" [];
+    \"x00\" -> \"z00 gate\" [];
+    \"z00 gate\" -> \"z00\" [label=\"out\" color=red];
+}
+"
+        );
+    }
+
+    #[test]
+    fn quotes() {
+        let mut dot = Dot::new(false);
+        dot.node(r#"say "hi""#, Some(r"back\slash".to_string()));
+        dot.edge(r#"say "hi""#, r"a\", Some(r#""\"#.to_string()));
+
+        assert_eq!(
+            dot.to_string(),
+            r#"graph {
+    "say \"hi\"" [label="back\\slash"];
+    "say \"hi\"" -- "a\\" [label="\"\\"];
+}
+"#
+        );
+    }
+
+    #[test]
+    fn from_graph() {
+        let mut g = Graph::undirected();
+        g.add_edge(2, 1);
+        g.add_edge(2, 3);
+        g.add_node(4);
+
+        assert_eq!(
+            g.to_dot(|n| format!("n{n}")).to_string(),
+            "\
+graph {
+    \"n1\" [];
+    \"n2\" [];
+    \"n3\" [];
+    \"n4\" [];
+    \"n1\" -- \"n2\" [];
+    \"n2\" -- \"n3\" [];
+}
+"
+        );
+    }
+}
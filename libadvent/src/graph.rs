@@ -0,0 +1,611 @@
+use crate::dot::Dot;
+use itertools::Itertools;
+use std::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    fmt,
+};
+
+// ordered maps so that every traversal (and therefore every answer) is deterministic
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Graph<N> {
+    out: BTreeMap<N, BTreeSet<N>>,
+    inc: BTreeMap<N, BTreeSet<N>>,
+    directed: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle<N>(pub Vec<N>);
+
+impl<N: fmt::Debug> fmt::Display for Cycle<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cycle: ")?;
+
+        for n in &self.0 {
+            write!(f, "{n:?} -> ")?;
+        }
+
+        write!(f, "{:?}", self.0[0])
+    }
+}
+
+impl<N: Copy + Ord> Graph<N> {
+    pub fn directed() -> Self {
+        Self {
+            out: BTreeMap::new(),
+            inc: BTreeMap::new(),
+            directed: true,
+        }
+    }
+
+    pub fn undirected() -> Self {
+        Self {
+            directed: false,
+            ..Self::directed()
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn len(&self) -> usize {
+        self.out.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.out.is_empty()
+    }
+
+    pub fn add_node(&mut self, n: N) {
+        self.out.entry(n).or_default();
+
+        if self.directed {
+            self.inc.entry(n).or_default();
+        }
+    }
+
+    pub fn add_edge(&mut self, a: N, b: N) {
+        self.add_node(a);
+        self.add_node(b);
+
+        self.out.get_mut(&a).unwrap().insert(b);
+
+        if self.directed {
+            self.inc.get_mut(&b).unwrap().insert(a);
+        } else {
+            self.out.get_mut(&b).unwrap().insert(a);
+        }
+    }
+
+    pub fn remove_edge(&mut self, a: N, b: N) {
+        if !self.contains_edge(a, b) {
+            return;
+        }
+
+        self.out.get_mut(&a).unwrap().remove(&b);
+
+        if self.directed {
+            self.inc.get_mut(&b).unwrap().remove(&a);
+        } else {
+            self.out.get_mut(&b).unwrap().remove(&a);
+        }
+    }
+
+    pub fn contains_node(&self, n: N) -> bool {
+        self.out.contains_key(&n)
+    }
+
+    pub fn contains_edge(&self, a: N, b: N) -> bool {
+        self.out.get(&a).is_some_and(|outs| outs.contains(&b))
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = N> + Clone + '_ {
+        self.out.keys().copied()
+    }
+
+    // undirected edges are only yielded once, as (lo, hi)
+    pub fn edges(&self) -> impl Iterator<Item = (N, N)> + '_ {
+        self.out
+            .iter()
+            .flat_map(|(a, outs)| outs.iter().map(move |b| (*a, *b)))
+            .filter(|(a, b)| self.directed || a <= b)
+    }
+
+    pub fn neighbors(&self, n: N) -> impl Iterator<Item = N> + Clone + '_ {
+        self.out.get(&n).into_iter().flatten().copied()
+    }
+
+    pub fn predecessors(&self, n: N) -> impl Iterator<Item = N> + Clone + '_ {
+        let map = if self.directed { &self.inc } else { &self.out };
+        map.get(&n).into_iter().flatten().copied()
+    }
+
+    pub fn degree(&self, n: N) -> usize {
+        self.out.get(&n).map_or(0, BTreeSet::len)
+    }
+
+    pub fn subgraph(&self, nodes: impl IntoIterator<Item = N>) -> Self {
+        let keep = nodes
+            .into_iter()
+            .filter(|n| self.contains_node(*n))
+            .collect::<BTreeSet<_>>();
+
+        let mut sub = Self {
+            directed: self.directed,
+            ..Self::directed()
+        };
+
+        for n in keep.iter().copied() {
+            sub.add_node(n);
+
+            for m in self.neighbors(n).filter(|m| keep.contains(m)) {
+                sub.add_edge(n, m);
+            }
+        }
+
+        sub
+    }
+
+    pub fn to_dot(&self, name: impl Fn(N) -> String) -> Dot {
+        let mut dot = Dot::new(self.directed);
+
+        for n in self.nodes() {
+            dot.node(name(n), None);
+        }
+
+        for (a, b) in self.edges() {
+            dot.edge(name(a), name(b), None);
+        }
+
+        dot
+    }
+
+    pub fn bfs(&self, start: N) -> Vec<N> {
+        let mut seen = BTreeSet::from([start]);
+        let mut queue = VecDeque::from([start]);
+        let mut order = vec![];
+
+        while let Some(n) = queue.pop_front() {
+            order.push(n);
+
+            for m in self.neighbors(n) {
+                if seen.insert(m) {
+                    queue.push_back(m);
+                }
+            }
+        }
+
+        order
+    }
+
+    // preorder
+    pub fn dfs(&self, start: N) -> Vec<N> {
+        let mut seen = BTreeSet::new();
+        let mut stack = vec![start];
+        let mut order = vec![];
+
+        while let Some(n) = stack.pop() {
+            if !seen.insert(n) {
+                continue;
+            }
+
+            order.push(n);
+
+            // reversed so that the smallest neighbor is visited first
+            let next = self
+                .neighbors(n)
+                .filter(|m| !seen.contains(m))
+                .collect_vec();
+            stack.extend(next.into_iter().rev());
+        }
+
+        order
+    }
+
+    // weakly connected components if directed
+    pub fn components(&self) -> Vec<Vec<N>> {
+        let mut seen = BTreeSet::new();
+        let mut components = vec![];
+
+        for start in self.nodes() {
+            if !seen.insert(start) {
+                continue;
+            }
+
+            let mut component = vec![];
+            let mut stack = vec![start];
+
+            while let Some(n) = stack.pop() {
+                component.push(n);
+
+                for m in self.neighbors(n).chain(self.predecessors(n)) {
+                    if seen.insert(m) {
+                        stack.push(m);
+                    }
+                }
+            }
+
+            component.sort();
+            components.push(component);
+        }
+
+        components
+    }
+
+    // kahn's algorithm, smallest available node first
+    pub fn toposort(&self) -> Result<Vec<N>, Cycle<N>> {
+        assert!(self.directed, "Topological sort of an undirected graph");
+
+        let mut indeg = self
+            .inc
+            .iter()
+            .map(|(n, ins)| (*n, ins.len()))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut ready = indeg
+            .iter()
+            .filter(|(_, d)| **d == 0)
+            .map(|(n, _)| *n)
+            .collect::<BTreeSet<_>>();
+
+        let mut order = Vec::with_capacity(self.len());
+
+        while let Some(n) = ready.pop_first() {
+            order.push(n);
+            indeg.remove(&n);
+
+            for m in self.neighbors(n) {
+                let d = indeg.get_mut(&m).unwrap();
+                *d -= 1;
+
+                if *d == 0 {
+                    ready.insert(m);
+                }
+            }
+        }
+
+        if indeg.is_empty() {
+            return Ok(order);
+        }
+
+        // every leftover node has a leftover predecessor, so walking backwards must loop
+        let mut walk = vec![*indeg.keys().next().unwrap()];
+        let mut pos = BTreeMap::from([(walk[0], 0)]);
+
+        loop {
+            let cur = *walk.last().unwrap();
+            let prev = self
+                .predecessors(cur)
+                .find(|p| indeg.contains_key(p))
+                .unwrap();
+
+            if let Some(&i) = pos.get(&prev) {
+                let mut cycle = walk.split_off(i);
+                cycle.reverse();
+
+                return Err(Cycle(cycle));
+            }
+
+            pos.insert(prev, walk.len());
+            walk.push(prev);
+        }
+    }
+
+    // tarjan's, iterative so big inputs don't blow the stack
+    pub fn scc(&self) -> Vec<Vec<N>> {
+        let mut index = BTreeMap::<N, usize>::new();
+        let mut low = BTreeMap::<N, usize>::new();
+        let mut stack = vec![];
+        let mut on_stack = BTreeSet::new();
+        let mut sccs = vec![];
+
+        for root in self.nodes() {
+            if index.contains_key(&root) {
+                continue;
+            }
+
+            let mut work = vec![(root, self.neighbors(root))];
+            index.insert(root, index.len());
+            low.insert(root, index[&root]);
+            stack.push(root);
+            on_stack.insert(root);
+
+            while let Some((n, children)) = work.last_mut() {
+                let n = *n;
+
+                if let Some(m) = children.next() {
+                    if !index.contains_key(&m) {
+                        index.insert(m, index.len());
+                        low.insert(m, index[&m]);
+                        stack.push(m);
+                        on_stack.insert(m);
+                        work.push((m, self.neighbors(m)));
+                    } else if on_stack.contains(&m) {
+                        low.insert(n, low[&n].min(index[&m]));
+                    }
+
+                    continue;
+                }
+
+                work.pop();
+
+                if let Some((parent, _)) = work.last() {
+                    low.insert(*parent, low[parent].min(low[&n]));
+                }
+
+                if low[&n] == index[&n] {
+                    let mut scc = vec![];
+
+                    while let Some(m) = stack.pop() {
+                        on_stack.remove(&m);
+                        scc.push(m);
+
+                        if m == n {
+                            break;
+                        }
+                    }
+
+                    scc.sort();
+                    sccs.push(scc);
+                }
+            }
+        }
+
+        sccs
+    }
+
+    // all cliques of exactly k nodes, each sorted
+    pub fn cliques(&self, k: usize) -> Vec<Vec<N>> {
+        assert!(!self.directed, "Cliques of a directed graph");
+
+        fn extend<N: Copy + Ord>(
+            g: &Graph<N>,
+            k: usize,
+            clique: &mut Vec<N>,
+            candidates: BTreeSet<N>,
+            out: &mut Vec<Vec<N>>,
+        ) {
+            if clique.len() == k {
+                out.push(clique.clone());
+                return;
+            }
+
+            for n in candidates.iter().copied() {
+                let next = g
+                    .neighbors(n)
+                    .filter(|m| *m > n && candidates.contains(m))
+                    .collect();
+
+                clique.push(n);
+                extend(g, k, clique, next, out);
+                clique.pop();
+            }
+        }
+
+        let mut out = vec![];
+
+        if k > 0 {
+            extend(self, k, &mut vec![], self.nodes().collect(), &mut out);
+        }
+
+        out
+    }
+
+    pub fn maximal_cliques(&self) -> Vec<Vec<N>> {
+        let mut out = vec![];
+        self.bron_kerbosch(&mut |clique| out.push(clique.to_vec()));
+
+        out
+    }
+
+    pub fn max_clique(&self) -> Vec<N> {
+        let mut best = vec![];
+
+        self.bron_kerbosch(&mut |clique| {
+            if clique.len() > best.len() {
+                best = clique.to_vec();
+            }
+        });
+
+        best
+    }
+
+    // thank you wikipedia (with pivoting this time)
+    fn bron_kerbosch(&self, report: &mut impl FnMut(&[N])) {
+        assert!(!self.directed, "Cliques of a directed graph");
+
+        fn recurse<N: Copy + Ord>(
+            g: &Graph<N>,
+            r: &mut Vec<N>,
+            mut p: BTreeSet<N>,
+            mut x: BTreeSet<N>,
+            report: &mut impl FnMut(&[N]),
+        ) {
+            if p.is_empty() {
+                if x.is_empty() {
+                    let mut clique = r.clone();
+                    clique.sort();
+                    report(&clique);
+                }
+
+                return;
+            }
+
+            // pivot on whichever node knocks out the most candidates
+            let pivot = p
+                .iter()
+                .chain(x.iter())
+                .copied()
+                .max_by_key(|u| g.neighbors(*u).filter(|v| p.contains(v)).count())
+                .unwrap();
+
+            let pivot_n = g.neighbors(pivot).collect::<BTreeSet<_>>();
+
+            for v in p.difference(&pivot_n).copied().collect_vec() {
+                let n = g.neighbors(v).collect::<BTreeSet<_>>();
+
+                r.push(v);
+                recurse(
+                    g,
+                    r,
+                    p.intersection(&n).copied().collect(),
+                    x.intersection(&n).copied().collect(),
+                    report,
+                );
+                r.pop();
+
+                p.remove(&v);
+                x.insert(v);
+            }
+        }
+
+        recurse(
+            self,
+            &mut vec![],
+            self.nodes().collect(),
+            BTreeSet::new(),
+            report,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn directed(edges: &[(u32, u32)]) -> Graph<u32> {
+        let mut g = Graph::directed();
+
+        for (a, b) in edges {
+            g.add_edge(*a, *b);
+        }
+
+        g
+    }
+
+    fn undirected(edges: &[(u32, u32)]) -> Graph<u32> {
+        let mut g = Graph::undirected();
+
+        for (a, b) in edges {
+            g.add_edge(*a, *b);
+        }
+
+        g
+    }
+
+    // every node's successor in the cycle is one of its neighbors, the last wrapping to the first
+    fn is_cycle(g: &Graph<u32>, cycle: &[u32]) -> bool {
+        (0..cycle.len()).all(|i| g.contains_edge(cycle[i], cycle[(i + 1) % cycle.len()]))
+    }
+
+    #[test]
+    fn toposort() {
+        let mut g = directed(&[(1, 2), (1, 3), (3, 2)]);
+        g.add_node(4);
+
+        assert_eq!(g.toposort(), Ok(vec![1, 3, 2, 4]));
+        assert_eq!(Graph::<u32>::directed().toposort(), Ok(vec![]));
+    }
+
+    #[test]
+    fn toposort_cycle() {
+        let g = directed(&[(0, 1), (1, 2), (2, 3), (3, 1), (3, 4)]);
+        let Err(Cycle(cycle)) = g.toposort() else {
+            panic!("no cycle found");
+        };
+
+        assert_eq!(cycle, [2, 3, 1]);
+        assert!(is_cycle(&g, &cycle));
+    }
+
+    #[test]
+    fn toposort_cycle_upstream() {
+        // 1 is left over too, but only downstream of the cycle
+        let g = directed(&[(6, 7), (7, 8), (8, 6), (8, 1)]);
+        let cycle = g.toposort().unwrap_err();
+
+        assert_eq!(cycle, Cycle(vec![6, 7, 8]));
+        assert_eq!(cycle.to_string(), "cycle: 6 -> 7 -> 8 -> 6");
+    }
+
+    #[test]
+    fn toposort_self_loop() {
+        let g = directed(&[(1, 2), (2, 2)]);
+
+        assert_eq!(g.toposort(), Err(Cycle(vec![2])));
+    }
+
+    #[test]
+    #[should_panic]
+    fn toposort_undirected() {
+        let _ = undirected(&[(1, 2)]).toposort();
+    }
+
+    #[test]
+    fn scc() {
+        let mut g = directed(&[(1, 2), (2, 3), (3, 1), (3, 4), (4, 5), (5, 4), (5, 6)]);
+        g.add_node(9);
+
+        // reverse topological order, each sorted
+        assert_eq!(g.scc(), [vec![6], vec![4, 5], vec![1, 2, 3], vec![9]]);
+    }
+
+    #[test]
+    fn scc_acyclic() {
+        let g = directed(&[(1, 2), (1, 3), (3, 2)]);
+
+        assert_eq!(g.scc(), [[2], [3], [1]]);
+    }
+
+    #[test]
+    fn scc_long_chain() {
+        // a recursive tarjan's would overflow here
+        let edges = (0..50_000).map(|i| (i, i + 1)).chain([(50_000, 0)]);
+        let g = directed(&edges.collect_vec());
+
+        assert_eq!(g.scc(), [(0..=50_000).collect_vec()]);
+    }
+
+    // K4, a triangle sharing a corner with it, and an edge hanging off that
+    fn cliquey() -> Graph<u32> {
+        undirected(&[
+            (1, 2),
+            (1, 3),
+            (1, 4),
+            (2, 3),
+            (2, 4),
+            (3, 4),
+            (4, 5),
+            (4, 6),
+            (5, 6),
+            (6, 7),
+        ])
+    }
+
+    #[test]
+    fn cliques() {
+        let g = cliquey();
+
+        assert_eq!(
+            g.cliques(3),
+            [[1, 2, 3], [1, 2, 4], [1, 3, 4], [2, 3, 4], [4, 5, 6]]
+        );
+        assert_eq!(g.cliques(4), [[1, 2, 3, 4]]);
+        assert!(g.cliques(5).is_empty());
+        assert_eq!(g.cliques(2).len(), g.edges().count());
+        assert_eq!(g.cliques(1), g.nodes().map(|n| vec![n]).collect_vec());
+        assert!(g.cliques(0).is_empty());
+    }
+
+    #[test]
+    fn maximal_cliques() {
+        let g = cliquey();
+
+        assert_eq!(g.max_clique(), [1, 2, 3, 4]);
+        assert_eq!(
+            g.maximal_cliques().into_iter().sorted().collect_vec(),
+            [vec![1, 2, 3, 4], vec![4, 5, 6], vec![6, 7]]
+        );
+        assert!(Graph::<u32>::undirected().max_clique().is_empty());
+    }
+}
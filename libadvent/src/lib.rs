@@ -7,7 +7,20 @@ use std::{
 
 use itertools::Itertools;
 
+pub mod answer;
+pub mod disjoint;
+pub mod dot;
+pub mod graph;
 pub mod grid;
+pub mod math;
+pub mod memo;
+pub mod memory;
+pub mod params;
+pub mod solution;
+
+pub use answer::Answer;
+pub use params::Params;
+pub use solution::Solution;
 
 #[macro_export]
 macro_rules! problem_parser {
@@ -57,7 +70,7 @@ pub trait IsInput {
 
 pub struct TyParser<T: IsInput>(marker::PhantomData<T>);
 
-impl<'a, T: IsInput> Parser for TyParser<T> {
+impl<T: IsInput> Parser for TyParser<T> {
     type Output = T;
 
     fn parse(&mut self, s: &str) -> Self::Output {
@@ -0,0 +1,452 @@
+use std::{
+    cmp, fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+
+    a / gcd(a, b) * b
+}
+
+pub fn lcm_all(iter: impl IntoIterator<Item = u64>) -> u64 {
+    iter.into_iter().fold(1, lcm)
+}
+
+// (g, x, y) such that a*x + b*y = g
+pub fn egcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+
+    while r1 != 0 {
+        let q = r0 / r1;
+
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+
+    if r0 < 0 {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+pub fn mod_inv(a: i128, m: i128) -> Option<i128> {
+    let (g, x, _) = egcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+// solves x = r (mod m) for every (r, m), moduli don't need to be coprime
+// returns (x, lcm of the moduli) with x in 0..lcm
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    let mut acc = (0, 1);
+
+    for (r, m) in congruences {
+        let (r0, m0) = acc;
+        let (g, p, _) = egcd(m0, m);
+
+        if (r - r0) % g != 0 {
+            return None;
+        }
+
+        let lcm = m0 / g * m;
+        let k = ((r - r0) / g).rem_euclid(m / g) * p.rem_euclid(m / g) % (m / g);
+
+        acc = ((r0 + m0 * k).rem_euclid(lcm), lcm);
+    }
+
+    Some(acc)
+}
+
+// no floats here, log10 rounds badly near powers of ten
+pub trait Digits: Sized {
+    fn num_digits(self) -> u32;
+    fn concat_digits(self, other: Self) -> Self;
+}
+
+macro_rules! impl_digits {
+    ($($t:ty),*) => {
+        $(
+            impl Digits for $t {
+                fn num_digits(self) -> u32 {
+                    self.checked_ilog10().unwrap_or(0) + 1
+                }
+
+                fn concat_digits(self, other: Self) -> Self {
+                    self * (10 as $t).pow(other.num_digits()) + other
+                }
+            }
+        )*
+    };
+}
+
+impl_digits!(u64, u128);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    pub const ZERO: Self = Self { num: 0, den: 1 };
+    pub const ONE: Self = Self { num: 1, den: 1 };
+
+    pub fn new(num: i128, den: i128) -> Self {
+        assert_ne!(den, 0, "Rational with a zero denominator");
+
+        let g = egcd(num, den).0.max(1);
+        let sign = den.signum();
+
+        Self {
+            num: sign * num / g,
+            den: sign * den / g,
+        }
+    }
+
+    pub fn num(&self) -> i128 {
+        self.num
+    }
+
+    pub fn den(&self) -> i128 {
+        self.den
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.num == 0
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.den == 1
+    }
+
+    pub fn to_integer(&self) -> Option<i128> {
+        self.is_integer().then_some(self.num)
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(n: i128) -> Self {
+        Self { num: n, den: 1 }
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Self::from(n as i128)
+    }
+}
+
+impl From<isize> for Rational {
+    fn from(n: isize) -> Self {
+        Self::from(n as i128)
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.num * rhs.den + rhs.num * self.den, self.den * rhs.den)
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.num * rhs.num, self.den * rhs.den)
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::new(self.num * rhs.den, self.den * rhs.num)
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        (self.num * other.den).cmp(&(other.num * self.den))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LinearSolution {
+    None,
+    Unique(Vec<Rational>),
+    Infinite,
+}
+
+// gaussian elimination over the rationals on a * x = b
+pub fn solve_linear<T: Copy + Into<Rational>>(a: &[Vec<T>], b: &[T]) -> LinearSolution {
+    assert_eq!(a.len(), b.len(), "Every equation needs a right hand side");
+
+    let vars = a.first().map_or(0, Vec::len);
+    let mut rows = a
+        .iter()
+        .zip(b)
+        .map(|(row, rhs)| {
+            assert_eq!(row.len(), vars, "Equations have different lengths");
+
+            row.iter()
+                .chain([rhs])
+                .map(|n| (*n).into())
+                .collect::<Vec<Rational>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut rank = 0;
+
+    for col in 0..vars {
+        let Some(pivot) = (rank..rows.len()).find(|r| !rows[*r][col].is_zero()) else {
+            continue;
+        };
+
+        rows.swap(rank, pivot);
+
+        let lead = rows[rank][col];
+        rows[rank].iter_mut().for_each(|n| *n = *n / lead);
+
+        for r in 0..rows.len() {
+            let factor = rows[r][col];
+
+            if r == rank || factor.is_zero() {
+                continue;
+            }
+
+            for c in col..=vars {
+                let sub = rows[rank][c] * factor;
+                rows[r][c] = rows[r][c] - sub;
+            }
+        }
+
+        rank += 1;
+    }
+
+    // 0 = nonzero
+    if rows[rank..].iter().any(|row| !row[vars].is_zero()) {
+        return LinearSolution::None;
+    }
+
+    if rank < vars {
+        return LinearSolution::Infinite;
+    }
+
+    LinearSolution::Unique(rows[..vars].iter().map(|row| row[vars]).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(lcm_all([2, 3, 4, 5]), 60);
+        assert_eq!(lcm_all([]), 1);
+    }
+
+    #[test]
+    fn egcd_identity() {
+        for (a, b, gcd) in [
+            (240, 46, 2),
+            (46, 240, 2),
+            (0, 5, 5),
+            (5, 0, 5),
+            (-12, 18, 6),
+            (17, -5, 1),
+            (-8, -12, 4),
+            (1 << 100, 6, 2),
+        ] {
+            let (g, x, y) = egcd(a, b);
+
+            assert_eq!(g, gcd, "egcd({a}, {b})");
+            assert_eq!(a * x + b * y, g, "egcd({a}, {b})");
+        }
+    }
+
+    #[test]
+    fn mod_inverse() {
+        assert_eq!(mod_inv(3, 11), Some(4));
+        assert_eq!(mod_inv(-3, 11), Some(7));
+        assert_eq!(mod_inv(14, 11), Some(4));
+        assert_eq!(mod_inv(6, 9), None);
+        assert_eq!(mod_inv(0, 7), None);
+    }
+
+    #[test]
+    fn crt_coprime() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt([]), Some((0, 1)));
+
+        let (p, q) = (1_000_000_007, 998_244_353);
+        let (x, m) = crt([(1, p), (2, q)]).unwrap();
+
+        assert_eq!(m, p * q);
+        assert_eq!((x % p, x % q), (1, 2));
+    }
+
+    #[test]
+    fn crt_not_coprime() {
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(3, 6), (3, 6)]), Some((3, 6)));
+        // odd and even at once
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+    }
+
+    #[test]
+    fn digits() {
+        for (n, digits) in [
+            (0u64, 1),
+            (9, 1),
+            (10, 2),
+            (99, 2),
+            (100, 3),
+            (999, 3),
+            (1000, 4),
+            (10u64.pow(19) - 1, 19),
+            (10u64.pow(19), 20),
+            (u64::MAX, 20),
+        ] {
+            assert_eq!(n.num_digits(), digits, "{n}");
+        }
+
+        assert_eq!(10u128.pow(38).num_digits(), 39);
+        assert_eq!(u128::MAX.num_digits(), 39);
+    }
+
+    #[test]
+    fn concat_digits() {
+        assert_eq!(12u64.concat_digits(345), 12345);
+        assert_eq!(9u64.concat_digits(10), 910);
+        assert_eq!(99u64.concat_digits(100), 99100);
+        assert_eq!(100u64.concat_digits(100), 100100);
+        assert_eq!(5u64.concat_digits(0), 50);
+        assert_eq!(10u128.pow(20).concat_digits(1), 10u128.pow(21) + 1);
+    }
+
+    #[test]
+    fn rationals() {
+        assert_eq!(Rational::new(2, -4), Rational::new(-1, 2));
+        assert_eq!(Rational::new(2, -4).to_string(), "-1/2");
+        assert_eq!(Rational::new(0, -3), Rational::ZERO);
+        assert_eq!(Rational::new(6, 3).to_integer(), Some(2));
+        assert_eq!(
+            Rational::new(1, 3) + Rational::new(1, 6),
+            Rational::new(1, 2)
+        );
+        assert!(Rational::new(-1, 2) < Rational::new(1, 3));
+    }
+
+    fn ints(ns: &[i128]) -> LinearSolution {
+        LinearSolution::Unique(ns.iter().map(|n| Rational::from(*n)).collect())
+    }
+
+    #[test]
+    fn linear_unique() {
+        assert_eq!(
+            solve_linear(&[vec![2i64, 1], vec![1, -1]], &[5, 1]),
+            ints(&[2, 1])
+        );
+        assert_eq!(
+            solve_linear(&[vec![1i64, 1], vec![1, -1]], &[1, 0]),
+            LinearSolution::Unique(vec![Rational::new(1, 2); 2])
+        );
+        // the first pivot is zero
+        assert_eq!(
+            solve_linear(&[vec![0i64, 1], vec![1, 0]], &[3, 4]),
+            ints(&[4, 3])
+        );
+        // more equations than unknowns, but consistent
+        assert_eq!(
+            solve_linear(&[vec![1i64, 0], vec![0, 1], vec![1, 1]], &[1, 2, 3]),
+            ints(&[1, 2])
+        );
+        assert_eq!(solve_linear::<i64>(&[], &[]), ints(&[]));
+    }
+
+    #[test]
+    fn linear_none() {
+        assert_eq!(
+            solve_linear(&[vec![1i64, 1], vec![2, 2]], &[1, 3]),
+            LinearSolution::None
+        );
+        assert_eq!(
+            solve_linear(&[vec![1i64, 0], vec![0, 1], vec![1, 1]], &[1, 2, 4]),
+            LinearSolution::None
+        );
+    }
+
+    #[test]
+    fn linear_infinite() {
+        assert_eq!(
+            solve_linear(&[vec![1i64, 1], vec![2, 2]], &[1, 2]),
+            LinearSolution::Infinite
+        );
+        // y never shows up
+        assert_eq!(
+            solve_linear(&[vec![1i64, 0], vec![2, 0]], &[1, 2]),
+            LinearSolution::Infinite
+        );
+        assert_eq!(
+            solve_linear(&[vec![0i64, 0]], &[0]),
+            LinearSolution::Infinite
+        );
+    }
+}
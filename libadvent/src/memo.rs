@@ -0,0 +1,263 @@
+use std::{
+    borrow::Borrow,
+    collections::HashMap,
+    fmt,
+    hash::Hash,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        RwLock,
+    },
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+impl fmt::Display for MemoStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} hits, {} misses", self.hits, self.misses)
+    }
+}
+
+// lives as long as the solve that owns it, unlike #[memoize] which is process-global
+#[derive(Clone, Debug)]
+pub struct Memo<K, V> {
+    map: HashMap<K, V>,
+    stats: MemoStats,
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self {
+            map: HashMap::new(),
+            stats: MemoStats::default(),
+        }
+    }
+
+    pub fn get<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let v = self.map.get(k).cloned();
+
+        if v.is_some() {
+            self.stats.hits += 1;
+        } else {
+            self.stats.misses += 1;
+        }
+
+        v
+    }
+
+    pub fn insert(&mut self, k: K, v: V) {
+        self.map.insert(k, v);
+    }
+
+    // f gets the memo back so it can recurse
+    pub fn get_or_insert_with(&mut self, k: K, f: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(v) = self.get(&k) {
+            return v;
+        }
+
+        let v = f(self);
+        self.insert(k, v.clone());
+
+        v
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.stats
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.stats = MemoStats::default();
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// shareable between rayon workers. the lock isn't held while computing,
+// so two threads may both compute a missing value (first write wins)
+#[derive(Debug)]
+pub struct SyncMemo<K, V> {
+    map: RwLock<HashMap<K, V>>,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+impl<K: Hash + Eq, V: Clone> SyncMemo<K, V> {
+    pub fn new() -> Self {
+        Self {
+            map: RwLock::new(HashMap::new()),
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn get<Q>(&self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let v = self.map.read().unwrap().get(k).cloned();
+
+        if v.is_some() {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+        }
+
+        v
+    }
+
+    pub fn insert(&self, k: K, v: V) {
+        self.map.write().unwrap().entry(k).or_insert(v);
+    }
+
+    pub fn get_or_insert_with(&self, k: K, f: impl FnOnce(&Self) -> V) -> V {
+        if let Some(v) = self.get(&k) {
+            return v;
+        }
+
+        let v = f(self);
+        self.insert(k, v.clone());
+
+        v
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.read().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        MemoStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    pub fn clear(&self) {
+        self.map.write().unwrap().clear();
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Default for SyncMemo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use rayon::prelude::*;
+
+    use super::*;
+
+    fn fib(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        memo.get_or_insert_with(n, |memo| {
+            if n < 2 {
+                n
+            } else {
+                fib(memo, n - 1) + fib(memo, n - 2)
+            }
+        })
+    }
+
+    #[test]
+    fn once_per_key() {
+        let mut memo = Memo::new();
+        let calls = Cell::new(0);
+
+        for k in [1, 2, 1, 3, 2, 1] {
+            let v = memo.get_or_insert_with(k, |_| {
+                calls.set(calls.get() + 1);
+                k * 10
+            });
+
+            assert_eq!(v, k * 10);
+        }
+
+        assert_eq!(calls.get(), 3);
+        assert_eq!(memo.len(), 3);
+        assert_eq!(memo.stats(), MemoStats { hits: 3, misses: 3 });
+    }
+
+    #[test]
+    fn recursion() {
+        let mut memo = Memo::new();
+
+        assert_eq!(fib(&mut memo, 90), 2880067194370816120);
+        // one miss per n, everything else was already there
+        assert_eq!(memo.len(), 91);
+        assert_eq!(memo.stats().misses, 91);
+
+        memo.clear();
+
+        assert!(memo.is_empty());
+        assert_eq!(memo.stats(), MemoStats::default());
+    }
+
+    #[test]
+    fn sync_threads() {
+        let memo = SyncMemo::new();
+        let calls = AtomicUsize::new(0);
+        let square = |k: usize| {
+            memo.get_or_insert_with(k, |_| {
+                calls.fetch_add(1, Ordering::Relaxed);
+                k * k
+            })
+        };
+
+        let keys = (0..1000).map(|i| i % 100).collect::<Vec<_>>();
+        let values = keys.par_iter().map(|&k| square(k)).collect::<Vec<_>>();
+
+        assert!(keys.iter().zip(&values).all(|(&k, &v)| v == k * k));
+        assert_eq!(memo.len(), 100);
+        // racing threads may compute the same key, but every key gets computed
+        assert!(calls.load(Ordering::Relaxed) >= 100);
+
+        // everything is cached now, so no more calls from any thread
+        let before = calls.load(Ordering::Relaxed);
+        keys.par_iter().for_each(|&k| assert_eq!(square(k), k * k));
+
+        assert_eq!(calls.load(Ordering::Relaxed), before);
+        assert_eq!(memo.stats().hits + memo.stats().misses, 2000);
+    }
+
+    #[test]
+    fn sync_first_write_wins() {
+        let memo = SyncMemo::new();
+
+        (0..8).into_par_iter().for_each(|i| memo.insert("k", i));
+        let first = memo.get("k").unwrap();
+
+        memo.insert("k", 100);
+
+        assert_eq!(memo.get("k"), Some(first));
+        assert_eq!(memo.len(), 1);
+    }
+}
@@ -0,0 +1,70 @@
+// knobs a day needs to run something other than the real input (grid sizes, thresholds, ...)
+pub trait Params: Default {
+    // every key `set` accepts
+    const KEYS: &'static [&'static str];
+
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String>;
+}
+
+// for days that don't need any
+impl Params for () {
+    const KEYS: &'static [&'static str] = &[];
+
+    fn set(&mut self, key: &str, _: &str) -> Result<(), String> {
+        Err(format!("unknown parameter {key:?}"))
+    }
+}
+
+// generates the struct, its Default from the given values, and a Params impl that parses each field
+// a field marked #[range(1..)] rejects values outside of it
+#[macro_export]
+macro_rules! params {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($(#[range($range:expr)])? $fvis:vis $field:ident: $ty:ty = $default:expr),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        $vis struct $name {
+            $($fvis $field: $ty),*
+        }
+
+        impl ::std::default::Default for $name {
+            fn default() -> Self {
+                Self {
+                    $($field: $default),*
+                }
+            }
+        }
+
+        impl ::libadvent::Params for $name {
+            const KEYS: &'static [&'static str] = &[$(stringify!($field)),*];
+
+            fn set(&mut self, key: &str, value: &str) -> ::std::result::Result<(), String> {
+                match key {
+                    $(
+                        stringify!($field) => {
+                            let value: $ty = value
+                                .trim()
+                                .parse()
+                                .map_err(|e| format!("{key}: {e}"))?;
+
+                            $(
+                                if !($range).contains(&value) {
+                                    return Err(format!("{key}: {value} is not in {:?}", $range));
+                                }
+                            )?
+
+                            self.$field = value;
+                        }
+                    )*
+                    _ => return Err(format!("unknown parameter {key:?}")),
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
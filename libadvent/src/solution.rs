@@ -0,0 +1,206 @@
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    dot::Dot,
+    memory::{Memory, Phase},
+    Answer, Params,
+};
+
+// one day's puzzle, usually implemented through solution!
+pub trait Solution {
+    type Input;
+    type Params: Params;
+
+    fn parse(input: &str) -> Self::Input;
+    fn level1(input: Self::Input, params: &Self::Params) -> Answer;
+    fn level2(input: Self::Input, params: &Self::Params) -> Answer;
+
+    // whether the params make sense for this input, before a level runs into them
+    fn check(_input: &Self::Input, _params: &Self::Params) -> Result<(), String> {
+        Ok(())
+    }
+
+    // the parsed input as a graph, for the days where looking at one helps
+    fn dot(_input: &Self::Input) -> Option<Dot> {
+        None
+    }
+}
+
+pub struct Run {
+    pub output: Answer,
+    pub parse: Duration,
+    pub run: Duration,
+    // all zero unless the binary counts allocations
+    pub parse_memory: Memory,
+    pub run_memory: Memory,
+}
+
+// key=value pairs for the day's Params, applied in order
+pub type Overrides = [(String, String)];
+pub type Level = fn(&str, &Overrides) -> Result<Run, String>;
+
+fn run<S: Solution, const LEVEL: u8>(input: &str, overrides: &Overrides) -> Result<Run, String> {
+    let mut params = S::Params::default();
+
+    for (key, value) in overrides {
+        params.set(key, value)?;
+    }
+
+    let phase = Phase::start();
+    let timer = Instant::now();
+    let parsed = S::parse(input);
+    let parse = timer.elapsed();
+    let parse_memory = phase.finish();
+
+    S::check(&parsed, &params)?;
+
+    let phase = Phase::start();
+    let timer = Instant::now();
+    let output = if LEVEL == 1 {
+        S::level1(parsed, &params)
+    } else {
+        S::level2(parsed, &params)
+    };
+    let run = timer.elapsed();
+    let run_memory = phase.finish();
+
+    Ok(Run {
+        output,
+        parse,
+        run,
+        parse_memory,
+        run_memory,
+    })
+}
+
+fn dot<S: Solution>(input: &str) -> Option<Dot> {
+    S::dot(&S::parse(input))
+}
+
+// "day07" -> 7, "y2024" -> 2024
+pub const fn number(name: &str) -> usize {
+    let bytes = name.as_bytes();
+    let mut n = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i].is_ascii_digit() {
+            n = n * 10 + (bytes[i] - b'0') as usize;
+        }
+
+        i += 1;
+    }
+
+    n
+}
+
+// a Solution with the types erased, so every day fits in one registry
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub day: usize,
+    pub name: &'static str,
+    pub params: &'static [&'static str],
+    pub levels: [Level; 2],
+    pub dot: fn(&str) -> Option<Dot>,
+}
+
+impl Entry {
+    pub const fn new<S: Solution>(name: &'static str) -> Self {
+        Self {
+            day: number(name),
+            name,
+            params: S::Params::KEYS,
+            levels: [run::<S, 1>, run::<S, 2>],
+            dot: dot::<S>,
+        }
+    }
+
+    pub fn level(&self, level: u8) -> Level {
+        self.levels[level as usize - 1]
+    }
+}
+
+impl fmt::Debug for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Entry")
+            .field("day", &self.day)
+            .field("name", &self.name)
+            .field("params", &self.params)
+            .finish()
+    }
+}
+
+// implements Solution for the module's `parser()`, `level1` and `level2` as a unit struct `Day`
+// `; dot = f` also lets the runner draw the parsed input with f(&input)
+// `; check = f` runs f(&input, &params) before either level, an Err fails the level
+#[macro_export]
+macro_rules! solution {
+    ($input:ty $(; dot = $dot:expr)? $(; check = $check:expr)?) => {
+        pub struct Day;
+
+        impl ::libadvent::Solution for Day {
+            type Input = $input;
+            type Params = ();
+
+            fn parse(input: &str) -> Self::Input {
+                ::libadvent::Parser::parse(&mut parser(), input)
+            }
+
+            fn level1(input: Self::Input, _: &()) -> ::libadvent::Answer {
+                level1(input).into()
+            }
+
+            fn level2(input: Self::Input, _: &()) -> ::libadvent::Answer {
+                level2(input).into()
+            }
+
+            $(
+                fn dot(input: &Self::Input) -> Option<::libadvent::dot::Dot> {
+                    Some($dot(input))
+                }
+            )?
+
+            $(
+                fn check(input: &Self::Input, params: &Self::Params) -> Result<(), String> {
+                    $check(input, params)
+                }
+            )?
+        }
+    };
+
+    ($input:ty, $params:ty $(; dot = $dot:expr)? $(; check = $check:expr)?) => {
+        pub struct Day;
+
+        impl ::libadvent::Solution for Day {
+            type Input = $input;
+            type Params = $params;
+
+            fn parse(input: &str) -> Self::Input {
+                ::libadvent::Parser::parse(&mut parser(), input)
+            }
+
+            fn level1(input: Self::Input, params: &$params) -> ::libadvent::Answer {
+                level1(input, params).into()
+            }
+
+            fn level2(input: Self::Input, params: &$params) -> ::libadvent::Answer {
+                level2(input, params).into()
+            }
+
+            $(
+                fn dot(input: &Self::Input) -> Option<::libadvent::dot::Dot> {
+                    Some($dot(input))
+                }
+            )?
+
+            $(
+                fn check(input: &Self::Input, params: &Self::Params) -> Result<(), String> {
+                    $check(input, params)
+                }
+            )?
+        }
+    };
+}
@@ -0,0 +1,81 @@
+use std::{collections::BTreeMap, fmt, fs, io, path::PathBuf};
+
+use colored::Colorize;
+use libadvent::Answer;
+
+use crate::dataset;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Correct,
+    Wrong(String),
+    Unknown,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "{}", "correct".green()),
+            Self::Wrong(expected) => write!(f, "{}", format!("wrong, expected {expected}").red()),
+            Self::Unknown => write!(f, "{}", "unknown".yellow()),
+        }
+    }
+}
+
+impl Status {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Correct => "correct",
+            Self::Wrong(_) => "wrong",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+pub fn status(expected: Option<&str>, output: &Answer) -> Status {
+    match expected {
+        Some(expected) if output.matches(expected) => Status::Correct,
+        Some(expected) => Status::Wrong(expected.to_string()),
+        None => Status::Unknown,
+    }
+}
+
+// dataset/<year>/answers.toml, keyed like the cli (05b = "123")
+pub struct Answers {
+    known: BTreeMap<String, String>,
+}
+
+fn path() -> PathBuf {
+    dataset().join("answers.toml")
+}
+
+impl Answers {
+    pub fn load() -> io::Result<Self> {
+        let known = match fs::read_to_string(path()) {
+            Ok(s) => toml::from_str(&s).map_err(io::Error::other)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e),
+        };
+
+        Ok(Self { known })
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.known.get(key).map(String::as_str)
+    }
+
+    pub fn check(&self, key: &str, output: &Answer) -> Status {
+        status(self.get(key), output)
+    }
+
+    pub fn set(&mut self, key: String, output: &Answer) {
+        self.known.insert(key, output.to_string());
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        fs::write(
+            path(),
+            toml::to_string(&self.known).map_err(io::Error::other)?,
+        )
+    }
+}
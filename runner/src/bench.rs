@@ -0,0 +1,263 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::PathBuf,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cli::{BenchOpts, Target},
+    colorize,
+    config::config,
+    dataset, entry, jobs,
+    output::{self, BenchRecord},
+    read_input, solve_input,
+};
+
+// everything is in nanoseconds
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    fn new(samples: &mut [Duration]) -> Self {
+        samples.sort();
+
+        let ns = samples
+            .iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        let n = ns.len() as f64;
+        let mean = ns.iter().sum::<f64>() / n;
+        let var = ns.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+
+        let mid = ns.len() / 2;
+        let median = if ns.len() % 2 == 0 {
+            (ns[mid - 1] + ns[mid]) / 2.0
+        } else {
+            ns[mid]
+        };
+
+        Self {
+            min: ns[0],
+            median,
+            mean,
+            stddev: var.sqrt(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Entry {
+    pub runs: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+fn dur(ns: f64) -> Duration {
+    Duration::from_nanos(ns as u64)
+}
+
+fn baseline_path() -> PathBuf {
+    dataset().join("bench.json")
+}
+
+fn load_baseline() -> BTreeMap<String, Entry> {
+    fs::read_to_string(baseline_path())
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn measure(day: usize, level: u8, input: &str, opts: &BenchOpts) -> Entry {
+    // no overrides, so the level can't fail
+    let level = |input| entry(day).level(level)(input, &[]).unwrap();
+
+    for _ in 0..opts.warmup {
+        level(input);
+    }
+
+    let (max, budget) = match (opts.iterations, opts.time) {
+        (None, None) => (usize::MAX, Some(Duration::from_secs(3))),
+        (n, t) => (n.unwrap_or(usize::MAX), t.map(Duration::from_secs_f64)),
+    };
+
+    let mut parse = vec![];
+    let mut solve = vec![];
+    let start = Instant::now();
+
+    // always at least one sample, even if a single run blows the budget
+    while parse.is_empty()
+        || parse.len() < max && budget.is_none_or(|budget| start.elapsed() < budget)
+    {
+        let run = level(input);
+        parse.push(run.parse);
+        solve.push(run.run);
+    }
+
+    Entry {
+        runs: parse.len(),
+        parse: Stats::new(&mut parse),
+        solve: Stats::new(&mut solve),
+    }
+}
+
+// the median's change against the baseline, in percent
+fn change(now: Stats, was: Stats) -> f64 {
+    (now.median - was.median) / was.median * 100.0
+}
+
+// true if this is a regression
+fn line(
+    name: &str,
+    now: Stats,
+    was: Option<Stats>,
+    thresholds: (u64, u64),
+    threshold: f64,
+) -> bool {
+    print!(
+        "\t{name}:\tmin {:<12} median {} mean {:<12} ±{:<12}",
+        format!("{:?}", dur(now.min)),
+        colorize(dur(now.median), thresholds, 12),
+        format!("{:?}", dur(now.mean)),
+        format!("{:?}", dur(now.stddev)),
+    );
+
+    let Some(was) = was else {
+        println!();
+        return false;
+    };
+
+    let change = change(now, was);
+    let disp = format!("{change:+.1}%");
+
+    if change > threshold {
+        println!("{} {}", disp.red(), "regression".red().bold());
+        true
+    } else if change < -threshold {
+        println!("{}", disp.green());
+        false
+    } else {
+        println!("{disp}");
+        false
+    }
+}
+
+pub fn bench(opts: BenchOpts) -> ExitCode {
+    let Some(jobs) = jobs(opts.target) else {
+        return ExitCode::FAILURE;
+    };
+
+    let mut baseline = load_baseline();
+    let mut code = ExitCode::SUCCESS;
+
+    for (day, level) in jobs {
+        let key = Target::Day(day, Some(level)).to_string();
+        let name = entry(day).name;
+
+        let input = match read_input(day, None) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{}", format!("Day {day:02} level {level}: {e}").red());
+                code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        // measuring is in process, so a level that panics or hangs is caught by one isolated run first
+        if let Err(e) = solve_input(day, level, &input, &[]) {
+            eprintln!("{}", format!("{name} level {level}: {e}").red());
+            code = ExitCode::FAILURE;
+            continue;
+        }
+
+        let entry = measure(day, level, &input, &opts);
+        let was = baseline.get(&key);
+
+        if output::machine() {
+            let parse_change = was.map(|was| change(entry.parse, was.parse));
+            let solve_change = was.map(|was| change(entry.solve, was.solve));
+            let regression = [parse_change, solve_change]
+                .iter()
+                .flatten()
+                .any(|change| *change > opts.threshold);
+
+            if regression {
+                code = ExitCode::FAILURE;
+            }
+
+            output::emit(&BenchRecord {
+                year: config().year,
+                day,
+                level,
+                name: name.to_string(),
+                runs: entry.runs,
+                parse: entry.parse,
+                solve: entry.solve,
+                parse_change,
+                solve_change,
+                regression,
+            });
+
+            if opts.save {
+                baseline.insert(key, entry);
+            }
+
+            continue;
+        }
+
+        println!(
+            "{} - {} ({} runs)",
+            name.blue(),
+            format!("Level {level}").magenta(),
+            entry.runs
+        );
+
+        let slower = [
+            line(
+                "parse",
+                entry.parse,
+                was.map(|w| w.parse),
+                config().thresholds.parse,
+                opts.threshold,
+            ),
+            line(
+                "solve",
+                entry.solve,
+                was.map(|w| w.solve),
+                config().thresholds.run,
+                opts.threshold,
+            ),
+        ];
+
+        if slower.contains(&true) {
+            code = ExitCode::FAILURE;
+        }
+
+        if opts.save {
+            baseline.insert(key, entry);
+        }
+    }
+
+    if opts.save {
+        let json = serde_json::to_string_pretty(&baseline).unwrap();
+
+        match fs::write(baseline_path(), json) {
+            Ok(()) => eprintln!("{}", "Saved baseline".green()),
+            Err(e) => {
+                eprintln!("{}", format!("Could not save baseline: {e}").red());
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    code
+}
@@ -0,0 +1,314 @@
+use std::{fmt, path::PathBuf, str::FromStr};
+
+use clap::{error::ErrorKind, Args, CommandFactory, Parser, Subcommand, ValueEnum};
+
+use crate::client;
+
+#[derive(Parser, Debug)]
+#[command(version, about = "Advent of Code runner")]
+pub struct Cli {
+    /// Use this config instead of the nearest advent.toml
+    #[arg(long, global = true)]
+    pub config: Option<PathBuf>,
+
+    /// Directory with the inputs, answers and examples
+    #[arg(long, global = true)]
+    pub dataset: Option<PathBuf>,
+
+    /// Keep the session token here ($AOC_TOKEN wins over it)
+    #[arg(long, global = true)]
+    pub token_file: Option<PathBuf>,
+
+    /// Puzzle year: which solutions run, which dataset/<year> is used, and what gets fetched
+    #[arg(long, global = true)]
+    pub year: Option<u32>,
+
+    /// Give up on a level after this many seconds, 0 waits forever (60 by default)
+    #[arg(long, global = true, value_name = "SECS")]
+    pub timeout: Option<f64>,
+
+    /// Threads for the solutions' rayon pools (all cores by default)
+    #[arg(long, global = true, value_parser = clap::value_parser!(u32).range(1..))]
+    pub threads: Option<u32>,
+
+    /// Print results as colored text, or one json or csv record per level for scripts
+    #[arg(long, global = true, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+
+    // no subcommand drops into the interactive menu
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+impl Cli {
+    // parse, plus what clap can't check by itself; exits the same way on an error
+    pub fn parse_checked() -> Self {
+        let cli = Self::parse();
+
+        if let Some(Command::Run { schedule, .. } | Command::Verify { schedule, .. }) = &cli.command
+        {
+            if schedule.check_deterministic && schedule.repeat < 2 {
+                Self::command()
+                    .error(
+                        ErrorKind::ArgumentConflict,
+                        "--check-deterministic needs --repeat 2 or more",
+                    )
+                    .exit();
+            }
+        }
+
+        cli
+    }
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Run one level (05b), both levels of a day (05), or everything (all)
+    Run {
+        target: Target,
+
+        /// Read the puzzle input from this file instead of the dataset, - for stdin;
+        /// repeat it to run the day over every one
+        #[arg(long = "input", value_name = "FILE")]
+        inputs: Vec<PathBuf>,
+
+        /// Record the answers in dataset/<year>/answers.toml as correct
+        #[arg(long)]
+        accept: bool,
+
+        /// Run the dataset examples instead, or only dayNN.exampleK.txt
+        #[arg(long, value_name = "K", num_args = 0..=1, conflicts_with_all = ["inputs", "accept"])]
+        example: Option<Option<String>>,
+
+        /// Override one of the day's parameters (size=7)
+        #[arg(long = "param", value_name = "KEY=VALUE", value_parser = param)]
+        params: Vec<(String, String)>,
+
+        #[command(flatten)]
+        schedule: Schedule,
+    },
+
+    /// Rebuild and rerun a day (16) or level (16b) whenever its source, libadvent or its input changes
+    Watch {
+        target: Target,
+
+        /// Run the dataset examples instead, or only dayNN.exampleK.txt
+        #[arg(long, value_name = "K", num_args = 0..=1)]
+        example: Option<Option<String>>,
+    },
+
+    /// Show every registered day and its parameters
+    List,
+
+    /// Check every recorded answer, failing on any mismatch
+    Verify {
+        #[arg(default_value = "all")]
+        target: Target,
+
+        #[command(flatten)]
+        schedule: Schedule,
+    },
+
+    /// Time a target many times and compare against the saved baseline
+    Bench(BenchOpts),
+
+    /// Download every missing input into the dataset
+    Fetch {
+        /// Instead save this day's description as dataset/<year>/dayNN.md, and its code blocks as examples
+        #[arg(long, value_name = "DAY", value_parser = clap::value_parser!(u8).range(1..=25))]
+        puzzle: Option<u8>,
+
+        /// Convert this saved puzzle page instead of downloading it
+        #[arg(long, requires = "puzzle")]
+        html: Option<PathBuf>,
+
+        #[command(flatten)]
+        client: ClientOpts,
+    },
+
+    /// Solve one level (05b) and send the answer to the site
+    Submit {
+        target: Target,
+
+        #[command(flatten)]
+        client: ClientOpts,
+    },
+
+    /// Draw a day's parsed input as a graph, as DOT on stdout or into a file
+    Dot {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// .dot is written as is, anything else (out.svg, out.png) is rendered by graphviz
+        out: Option<PathBuf>,
+
+        /// Read the puzzle input from this file instead of the dataset
+        #[arg(long, value_name = "FILE")]
+        input: Option<PathBuf>,
+    },
+
+    /// Start a day from solutions/src/_template.rs, with empty input and example files
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Also add an empty [dayNN.example1] table to dataset/<year>/examples.toml
+        #[arg(long)]
+        answers: bool,
+    },
+
+    /// Manage the session token
+    #[command(subcommand)]
+    Token(TokenAction),
+
+    // runs one level on stdin in its own process, for the runner itself
+    #[command(hide = true)]
+    Worker {
+        day: usize,
+        level: u8,
+
+        #[arg(long = "param", value_parser = param)]
+        params: Vec<(String, String)>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum TokenAction {
+    /// Show where the token comes from and ask the site whether it works
+    Check(ClientOpts),
+
+    /// Delete the token file
+    Clear,
+}
+
+#[derive(Args, Clone, Copy, Debug)]
+pub struct Schedule {
+    /// Run this many levels at once for run all and verify (their timings get noisier)
+    #[arg(long, short = 'j', default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    pub jobs: u32,
+
+    /// Run every level this many times and report the fastest
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    pub repeat: u32,
+
+    /// Fail a level that doesn't give the same answer on every repeat (needs --repeat 2 or more)
+    #[arg(long)]
+    pub check_deterministic: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct ClientOpts {
+    /// Site to talk to, e.g. a local stand-in server
+    #[arg(long, default_value = client::BASE_URL)]
+    pub base_url: String,
+
+    #[arg(long, default_value = client::USER_AGENT)]
+    pub user_agent: String,
+
+    /// Seconds between two requests, doubled on every retry
+    #[arg(long, default_value_t = client::DELAY, value_parser = non_negative)]
+    pub delay: f64,
+
+    /// How often a server error is retried
+    #[arg(long, default_value_t = client::RETRIES)]
+    pub retries: u32,
+}
+
+// the menu has no arguments to parse
+impl Default for ClientOpts {
+    fn default() -> Self {
+        Self {
+            base_url: client::BASE_URL.to_string(),
+            user_agent: client::USER_AGENT.to_string(),
+            delay: client::DELAY,
+            retries: client::RETRIES,
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+pub struct BenchOpts {
+    pub target: Target,
+
+    /// Untimed runs before measuring
+    #[arg(long, default_value_t = 3)]
+    pub warmup: usize,
+
+    /// Stop after this many measured runs
+    #[arg(long, short = 'n')]
+    pub iterations: Option<usize>,
+
+    /// Stop after this many seconds (3 if neither this nor --iterations is set)
+    #[arg(long, value_parser = non_negative)]
+    pub time: Option<f64>,
+
+    /// Store these results as the new baseline
+    #[arg(long)]
+    pub save: bool,
+
+    /// Median slowdown, in percent, that counts as a regression
+    #[arg(long, default_value_t = 10.0, value_parser = non_negative)]
+    pub threshold: f64,
+}
+
+// seconds and the like, anything Duration can hold
+fn non_negative(s: &str) -> Result<f64, String> {
+    match s.parse::<f64>() {
+        Ok(n) if n.is_finite() && n >= 0.0 => Ok(n),
+        Ok(_) => Err(format!("expected a finite number, 0 or above, got {s}")),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+fn param(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((key, value)) => Ok((key.trim().to_string(), value.trim().to_string())),
+        None => Err(format!("expected KEY=VALUE, got {s:?}")),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Target {
+    All,
+    Day(usize, Option<u8>),
+}
+
+impl FromStr for Target {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s == "all" {
+            return Ok(Self::All);
+        }
+
+        let (day, level) = match s.strip_suffix(['a', 'b']) {
+            Some(day) => (day, Some(if s.ends_with('a') { 1 } else { 2 })),
+            None => (s, None),
+        };
+
+        match day.parse() {
+            Ok(day @ 1..=25) => Ok(Self::Day(day, level)),
+            _ => Err(format!("expected DD, DD[a|b] or all, got {s:?}")),
+        }
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::All => write!(f, "all"),
+            Self::Day(day, None) => write!(f, "{day:02}"),
+            Self::Day(day, Some(1)) => write!(f, "{day:02}a"),
+            Self::Day(day, Some(_)) => write!(f, "{day:02}b"),
+        }
+    }
+}
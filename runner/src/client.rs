@@ -0,0 +1,366 @@
+use std::{
+    fmt, thread,
+    time::{Duration, Instant},
+};
+
+use reqwest::{blocking, StatusCode};
+
+use crate::{cli::ClientOpts, config::config};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u32 = 2024;
+pub const USER_AGENT: &str = concat!("advent24-runner/", env!("CARGO_PKG_VERSION"));
+pub const DELAY: f64 = 1.0;
+pub const RETRIES: u32 = 3;
+
+#[derive(Debug)]
+pub enum Error {
+    // the site answers 400 when the session cookie is missing or malformed
+    BadRequest,
+    // usually a puzzle that isn't unlocked yet
+    NotFound,
+    // 5xx that didn't go away after retrying, an expired session also ends up here
+    Server(StatusCode),
+    Status(StatusCode),
+    Http(reqwest::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BadRequest => write!(f, "Bad request (400), is the session token set?"),
+            Self::NotFound => write!(f, "Not found (404), the puzzle may not be unlocked yet"),
+            Self::Server(status) => write!(
+                f,
+                "Server error ({status}), the session token may be invalid or expired"
+            ),
+            Self::Status(status) => write!(f, "Unexpected response ({status})"),
+            Self::Http(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Self {
+        Self::Http(e)
+    }
+}
+
+// one connection pool for every request, spaced out by `delay` so we stay polite
+pub struct Client {
+    http: blocking::Client,
+    token: String,
+    base_url: String,
+    year: u32,
+    user_agent: String,
+    delay: Duration,
+    retries: u32,
+    last: Option<Instant>,
+}
+
+impl Client {
+    pub fn new(token: &str) -> Self {
+        Self {
+            http: blocking::Client::new(),
+            token: token.trim().to_string(),
+            base_url: BASE_URL.to_string(),
+            year: YEAR,
+            user_agent: USER_AGENT.to_string(),
+            delay: Duration::from_secs_f64(DELAY),
+            retries: RETRIES,
+            last: None,
+        }
+    }
+
+    pub fn base_url(mut self, url: &str) -> Self {
+        self.base_url = url.trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn year(mut self, year: u32) -> Self {
+        self.year = year;
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = user_agent.to_string();
+        self
+    }
+
+    // minimum time between two requests
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    pub fn configure(self, opts: &ClientOpts) -> Self {
+        self.base_url(&opts.base_url)
+            .year(config().year)
+            .user_agent(&opts.user_agent)
+            .delay(Duration::from_secs_f64(opts.delay))
+            .retries(opts.retries)
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}/{}/{path}", self.base_url, self.year)
+    }
+
+    fn throttle(&mut self) {
+        if let Some(last) = self.last {
+            thread::sleep(self.delay.saturating_sub(last.elapsed()));
+        }
+
+        self.last = Some(Instant::now());
+    }
+
+    // retries 5xx and connection errors with exponential backoff, starting at `delay`
+    fn send(
+        &mut self,
+        request: impl Fn(&blocking::Client) -> blocking::RequestBuilder,
+    ) -> Result<String, Error> {
+        let mut attempt = 0;
+
+        loop {
+            self.throttle();
+
+            let response = request(&self.http)
+                .header("cookie", format!("session={}", self.token))
+                .header("user-agent", &self.user_agent)
+                .send();
+
+            let retry = match response {
+                Ok(response) => match response.status() {
+                    status if status.is_success() => return Ok(response.text()?),
+                    StatusCode::BAD_REQUEST => return Err(Error::BadRequest),
+                    StatusCode::NOT_FOUND => return Err(Error::NotFound),
+                    status if status.is_server_error() => Error::Server(status),
+                    status => return Err(Error::Status(status)),
+                },
+                Err(e) if e.is_connect() || e.is_timeout() => Error::Http(e),
+                Err(e) => return Err(e.into()),
+            };
+
+            if attempt >= self.retries {
+                return Err(retry);
+            }
+
+            thread::sleep(self.delay * 2u32.pow(attempt));
+            attempt += 1;
+        }
+    }
+
+    pub fn get(&mut self, path: &str) -> Result<String, Error> {
+        let url = self.url(path);
+
+        self.send(|http| http.get(&url))
+    }
+
+    pub fn post(&mut self, path: &str, form: &[(&str, &str)]) -> Result<String, Error> {
+        let url = self.url(path);
+
+        self.send(|http| http.post(&url).form(form))
+    }
+
+    pub fn answer(&mut self, day: usize, level: u8, answer: &str) -> Result<String, Error> {
+        self.post(
+            &format!("day/{day}/answer"),
+            &[("level", &level.to_string()), ("answer", answer)],
+        )
+    }
+
+    pub fn puzzle(&mut self, day: usize) -> Result<String, Error> {
+        self.get(&format!("day/{day}"))
+    }
+
+    pub fn input(&mut self, day: usize) -> Result<String, Error> {
+        self.get(&format!("day/{day}/input"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::JoinHandle,
+    };
+
+    use super::*;
+
+    // a request as the server saw it
+    struct Request {
+        line: String,
+        headers: Vec<(String, String)>,
+        body: String,
+    }
+
+    impl Request {
+        fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str())
+        }
+    }
+
+    // answers one connection per response, in order, then hands back what it was sent
+    fn serve(responses: &[(u16, &str)]) -> (String, JoinHandle<Vec<Request>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let responses = responses
+            .iter()
+            .map(|(status, body)| (*status, body.to_string()))
+            .collect::<Vec<_>>();
+
+        let server = thread::spawn(move || {
+            let mut requests = vec![];
+
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+
+                let mut headers = vec![];
+
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+
+                    match header.trim_end().split_once(": ") {
+                        Some((key, value)) => headers.push((key.to_string(), value.to_string())),
+                        None => break,
+                    }
+                }
+
+                let mut request = Request {
+                    line: line.trim_end().to_string(),
+                    headers,
+                    body: String::new(),
+                };
+
+                let length = request
+                    .header("content-length")
+                    .map_or(0, |n| n.parse().unwrap());
+                let mut bytes = vec![0; length];
+                reader.read_exact(&mut bytes).unwrap();
+                request.body = String::from_utf8(bytes).unwrap();
+
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {status} Whatever\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+
+                requests.push(request);
+            }
+
+            requests
+        });
+
+        (url, server)
+    }
+
+    fn client(url: &str) -> Client {
+        Client::new(" abc123\n").base_url(url).delay(Duration::ZERO)
+    }
+
+    #[test]
+    fn input() {
+        let (url, server) = serve(&[(200, "1 2 3\n")]);
+
+        assert_eq!(client(&url).year(2023).input(7).unwrap(), "1 2 3\n");
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].line, "GET /2023/day/7/input HTTP/1.1");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+        assert_eq!(requests[0].header("user-agent"), Some(USER_AGENT));
+    }
+
+    #[test]
+    fn user_agent() {
+        let (url, server) = serve(&[(200, "")]);
+
+        client(&url)
+            .user_agent("someone@example.com")
+            .puzzle(1)
+            .unwrap();
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].line, "GET /2024/day/1 HTTP/1.1");
+        assert_eq!(
+            requests[0].header("user-agent"),
+            Some("someone@example.com")
+        );
+    }
+
+    #[test]
+    fn answer() {
+        let (url, server) = serve(&[(200, "<article>ok</article>")]);
+
+        assert_eq!(
+            client(&url).answer(5, 2, "1234").unwrap(),
+            "<article>ok</article>"
+        );
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].line, "POST /2024/day/5/answer HTTP/1.1");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+        assert_eq!(requests[0].body, "level=2&answer=1234");
+    }
+
+    #[test]
+    fn bad_request() {
+        let (url, server) = serve(&[(400, "")]);
+
+        assert!(matches!(client(&url).input(1), Err(Error::BadRequest)));
+        assert_eq!(server.join().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn not_found() {
+        let (url, server) = serve(&[(404, "")]);
+
+        assert!(matches!(client(&url).input(25), Err(Error::NotFound)));
+        assert_eq!(server.join().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn other_status() {
+        let (url, server) = serve(&[(429, "")]);
+
+        assert!(matches!(
+            client(&url).input(1),
+            Err(Error::Status(StatusCode::TOO_MANY_REQUESTS))
+        ));
+        assert_eq!(server.join().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn server_error_then_success() {
+        let (url, server) = serve(&[(500, ""), (503, ""), (200, "input")]);
+
+        assert_eq!(client(&url).input(1).unwrap(), "input");
+        assert_eq!(server.join().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn server_error_gives_up() {
+        let (url, server) = serve(&[(500, ""), (502, ""), (500, "")]);
+
+        assert!(matches!(
+            client(&url).retries(2).input(1),
+            Err(Error::Server(StatusCode::INTERNAL_SERVER_ERROR))
+        ));
+        assert_eq!(server.join().unwrap().len(), 3);
+    }
+}
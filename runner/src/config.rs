@@ -0,0 +1,126 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+    time::Duration,
+};
+
+use serde::Deserialize;
+
+use crate::{
+    cli::{Cli, Format},
+    client,
+};
+
+pub const FILE: &str = "advent.toml";
+pub const TIMEOUT: f64 = 60.0;
+
+// milliseconds, green below the first value, yellow below the second, red after that
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Thresholds {
+    pub parse: (u64, u64),
+    pub run: (u64, u64),
+    pub total: (u64, u64),
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self {
+            parse: (4, 8),
+            run: (75, 200),
+            total: (80, 210),
+        }
+    }
+}
+
+// advent.toml as written, paths are relative to the file
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+struct File {
+    dataset: Option<PathBuf>,
+    solutions: Option<PathBuf>,
+    token_file: Option<PathBuf>,
+    year: Option<u32>,
+    // seconds
+    timeout: Option<f64>,
+    thresholds: Thresholds,
+}
+
+#[derive(Debug)]
+pub struct Config {
+    // the advent.toml that was read, if any
+    pub file: Option<PathBuf>,
+    pub dataset: PathBuf,
+    // the solutions crate's src, for `new`
+    pub solutions: PathBuf,
+    pub token_file: PathBuf,
+    pub year: u32,
+    // none waits forever
+    pub timeout: Option<Duration>,
+    pub thresholds: Thresholds,
+    // only ever from the command line
+    pub format: Format,
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+// the nearest advent.toml in the current directory or one of its parents
+fn discover() -> Option<PathBuf> {
+    env::current_dir()
+        .ok()?
+        .ancestors()
+        .map(|dir| dir.join(FILE))
+        .find(|path| path.is_file())
+}
+
+impl Config {
+    fn load(cli: &Cli) -> io::Result<Self> {
+        let path = cli.config.clone().or_else(discover);
+        let (file, root) = match &path {
+            Some(path) => {
+                let s = fs::read_to_string(path)
+                    .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))?;
+                let file = toml::from_str::<File>(&s)
+                    .map_err(|e| io::Error::other(format!("{}: {e}", path.display())))?;
+
+                (file, path.parent().unwrap_or(Path::new("")).to_path_buf())
+            }
+            None => (File::default(), PathBuf::new()),
+        };
+
+        // the command line wins, its paths are relative to the current directory
+        Ok(Self {
+            file: path,
+            dataset: cli
+                .dataset
+                .clone()
+                .unwrap_or_else(|| root.join(file.dataset.unwrap_or("dataset".into()))),
+            solutions: root.join(file.solutions.unwrap_or("solutions/src".into())),
+            token_file: cli
+                .token_file
+                .clone()
+                .unwrap_or_else(|| root.join(file.token_file.unwrap_or(".token.txt".into()))),
+            year: cli.year.or(file.year).unwrap_or(client::YEAR),
+            timeout: match cli.timeout.or(file.timeout).unwrap_or(TIMEOUT) {
+                0.0 => None,
+                secs if secs > 0.0 => Some(Duration::from_secs_f64(secs)),
+                secs => return Err(io::Error::other(format!("bad timeout {secs}"))),
+            },
+            thresholds: file.thresholds,
+            format: cli.format,
+        })
+    }
+}
+
+pub fn init(cli: &Cli) -> io::Result<()> {
+    CONFIG
+        .set(Config::load(cli)?)
+        .expect("config is only loaded once");
+
+    Ok(())
+}
+
+pub fn config() -> &'static Config {
+    CONFIG.get().expect("config is loaded at startup")
+}
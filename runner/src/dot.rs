@@ -0,0 +1,60 @@
+use std::{
+    io::{self, Write},
+    path::Path,
+    process::ExitCode,
+};
+
+use colored::Colorize;
+use libadvent::dot::Dot;
+
+use crate::{lookup, read_input};
+
+// .dot (or .gv) is written as is, anything else is the format graphviz renders to
+fn save(dot: &Dot, out: &Path) -> io::Result<()> {
+    match out.extension().and_then(|e| e.to_str()) {
+        Some("dot" | "gv") => dot.write(out),
+        _ => dot.render(out),
+    }
+}
+
+pub fn dot(day: usize, out: Option<&Path>, file: Option<&Path>) -> ExitCode {
+    let Some(entry) = lookup(day) else {
+        eprintln!("{}", format!("Day {day:02} is not solved yet").red());
+        return ExitCode::FAILURE;
+    };
+
+    let input = match read_input(day, file) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", format!("Day {day:02}: {e}").red());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let Some(dot) = (entry.dot)(&input) else {
+        eprintln!("{}", format!("Day {day:02} has no graph to draw").red());
+        return ExitCode::FAILURE;
+    };
+
+    // fine to pipe into head
+    let Some(out) = out else {
+        return match io::stdout().lock().write_all(dot.to_string().as_bytes()) {
+            Err(e) if e.kind() != io::ErrorKind::BrokenPipe => {
+                eprintln!("{}", e.to_string().red());
+                ExitCode::FAILURE
+            }
+            _ => ExitCode::SUCCESS,
+        };
+    };
+
+    match save(&dot, out) {
+        Ok(()) => {
+            println!("{}", format!("Saved {}", out.display()).green());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", format!("{}: {e}", out.display()).red());
+            ExitCode::FAILURE
+        }
+    }
+}
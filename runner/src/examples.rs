@@ -0,0 +1,107 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs, io,
+    path::PathBuf,
+};
+
+use serde::Deserialize;
+
+use crate::dataset;
+
+// one entry of dataset/<year>/examples.toml, keyed by day and file name:
+//
+//     [day18.example1]
+//     a = "22"
+//     b = "6,1"
+//     params = { size = 7, bytes = 12 }
+//
+// params_a and params_b only apply to one level, on top of params
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Entry {
+    a: Option<toml::Value>,
+    b: Option<toml::Value>,
+    #[serde(default)]
+    params: BTreeMap<String, toml::Value>,
+    #[serde(default)]
+    params_a: BTreeMap<String, toml::Value>,
+    #[serde(default)]
+    params_b: BTreeMap<String, toml::Value>,
+}
+
+pub struct Example {
+    pub name: String,
+    pub path: PathBuf,
+    pub expected: [Option<String>; 2],
+    // per level, the shared ones first so the level's own win
+    pub params: [Vec<(String, String)>; 2],
+}
+
+impl Example {
+    // an example that only states one answer is usually only valid for that level
+    pub fn covers(&self, level: u8) -> bool {
+        self.expected == [None, None] || self.expected[level as usize - 1].is_some()
+    }
+}
+
+// toml lets people write `a = 22`, answers are compared as text
+fn text(value: toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s,
+        value => value.to_string(),
+    }
+}
+
+fn load_entries() -> io::Result<BTreeMap<String, BTreeMap<String, Entry>>> {
+    match fs::read_to_string(dataset().join("examples.toml")) {
+        Ok(s) => toml::from_str(&s).map_err(io::Error::other),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(e) => Err(e),
+    }
+}
+
+// every dayNN.example*.txt in the dataset, plus anything examples.toml mentions
+pub fn load(day: usize) -> io::Result<Vec<Example>> {
+    let prefix = format!("day{day:02}.");
+    let mut entries = load_entries()?
+        .remove(&format!("day{day:02}"))
+        .unwrap_or_default();
+
+    let mut names = entries.keys().cloned().collect::<BTreeSet<_>>();
+
+    for file in fs::read_dir(dataset())? {
+        let file = file?.file_name();
+        let Some(name) = file
+            .to_str()
+            .and_then(|f| f.strip_prefix(&prefix))
+            .and_then(|f| f.strip_suffix(".txt"))
+        else {
+            continue;
+        };
+
+        if name.starts_with("example") {
+            names.insert(name.to_string());
+        }
+    }
+
+    Ok(names
+        .into_iter()
+        .map(|name| {
+            let entry = entries.remove(&name).unwrap_or_default();
+
+            Example {
+                path: dataset().join(format!("{prefix}{name}.txt")),
+                expected: [entry.a.map(text), entry.b.map(text)],
+                params: [&entry.params_a, &entry.params_b].map(|level| {
+                    entry
+                        .params
+                        .iter()
+                        .chain(level)
+                        .map(|(k, v)| (k.clone(), text(v.clone())))
+                        .collect()
+                }),
+                name,
+            }
+        })
+        .collect())
+}
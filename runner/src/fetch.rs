@@ -0,0 +1,109 @@
+use colored::Colorize;
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+use std::{fmt, io};
+
+use crate::{
+    cli::ClientOpts,
+    client::{Client, Error},
+    puzzle, token,
+};
+
+pub fn client(opts: &ClientOpts, menu: bool) -> io::Result<Client> {
+    Ok(Client::new(&token::token(menu)?).configure(opts))
+}
+
+// the menu redraws its last line, the subcommand prints plain lines and its errors on stderr
+fn progress(menu: bool, line: impl fmt::Display) {
+    if menu {
+        println!("\x1b[1A╰─ {line}");
+    } else {
+        println!("{line}");
+    }
+}
+
+fn failure(menu: bool, line: impl fmt::Display) -> ExitCode {
+    if menu {
+        println!("\x1b[1A╰─ {}", line.to_string().red());
+    } else {
+        eprintln!("{}", line.to_string().red());
+    }
+
+    ExitCode::FAILURE
+}
+
+pub fn fetch(opts: &ClientOpts, menu: bool) -> ExitCode {
+    let mut client = match client(opts, menu) {
+        Ok(client) => client,
+        Err(e) => return failure(menu, e),
+    };
+
+    if let Err(e) = fs::create_dir_all(crate::dataset()) {
+        return failure(menu, format!("{}: {e}", crate::dataset().display()));
+    }
+
+    for day in 1..=25 {
+        // an empty file is only a placeholder, like the one `new` leaves
+        let path = crate::dataset().join(format!("day{day:02}.txt"));
+
+        if fs::metadata(&path).is_ok_and(|meta| meta.len() > 0) {
+            progress(menu, format!("Skipping Day {day:02}...").green());
+            continue;
+        }
+
+        progress(menu, format!("Fetching Day {day:02}...").yellow());
+
+        match client.input(day) {
+            Ok(input) => {
+                if let Err(e) = fs::write(&path, input) {
+                    return failure(menu, format!("{}: {e}", path.display()));
+                }
+            }
+            // later days aren't out yet either
+            Err(Error::NotFound) => break,
+            Err(e) => return failure(menu, format!("Day {day:02}: {e}")),
+        }
+    }
+
+    // in the menu it has to cover the longer line it replaces
+    let done = if menu {
+        "Fetched               "
+    } else {
+        "Fetched"
+    };
+    progress(menu, done.green());
+
+    ExitCode::SUCCESS
+}
+
+pub fn puzzle(day: usize, html: Option<&Path>, opts: &ClientOpts) -> ExitCode {
+    let html = match html {
+        Some(path) => fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display())),
+        None => client(opts, false)
+            .map_err(|e| e.to_string())
+            .and_then(|mut client| client.puzzle(day).map_err(|e| e.to_string())),
+    };
+
+    let written = match html
+        .and_then(|html| puzzle::save(&crate::dataset(), day, &html).map_err(|e| e.to_string()))
+    {
+        Ok(written) => written,
+        Err(e) => {
+            eprintln!("{}", format!("Day {day:02}: {e}").red());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("{}", format!("Saved day{day:02}.md").green());
+
+    if written.is_empty() {
+        println!("{}", "No new example blocks".yellow());
+    }
+
+    for file in written {
+        println!("{}", format!("Saved {file}").green());
+    }
+
+    ExitCode::SUCCESS
+}
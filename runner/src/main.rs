@@ -1,102 +1,52 @@
-use libadvent::Parser;
-use reqwest::StatusCode;
+mod answers;
+mod bench;
+mod cli;
+mod client;
+mod config;
+mod dot;
+mod examples;
+mod fetch;
+mod output;
+mod puzzle;
+mod scaffold;
+mod submit;
+mod table;
+mod token;
+mod watch;
+mod worker;
+
+use libadvent::{
+    memory::Memory,
+    solution::{Entry, Overrides, Run},
+    Answer,
+};
+use std::collections::BTreeMap;
+use std::env;
 use std::fs::OpenOptions;
-use std::io::{Read, Write};
-use std::path::Path;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
 use std::time::Duration;
 
+use answers::{Answers, Status};
+use cli::{Cli, ClientOpts, Command, Schedule, Target, TokenAction};
 use colored::Colorize;
-use paste::paste;
-use solutions::*;
+use config::config;
+use output::Record;
+use table::Table;
 
-macro_rules! runner {
-    (run $day:ident $level:tt $parsed:ident) => {
-        paste! {
-            $day::[<level $level>]($parsed)
-        }
-    };
-
-    (parse $day:ident) => {
-        $day::parser().parse(
-            input(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/../dataset/",
-                stringify!($day),
-                ".txt"
-            )).as_str()
-        )
-    };
-
-    ($($day:ident),*) => {
-        println!("╭─ Pick a day");
-        println!("│");
-        println!("├─ Format: DD[a|b]");
-        println!("├─ Example: 01a");
-        println!("├─ Or enter \"fetch\" to fetch all inputs");
-        println!("│");
-        print!("\n\n\n\n\n\x1b[5A╰─ ");
-
-        let mut input = String::new();
-        std::io::stdout().lock().flush().unwrap();
-        std::io::stdin().read_line(&mut input).unwrap();
-
-        if input.trim() == "fetch" {
-            println!("\x1b[1A╰─ {}", "Fetching...".yellow());
-            fetch();
-            return;
-        }
-
-        let mut iter = (1..).map(|i| (i + 1) / 2);
-
-        println!("\x1b[1A╰─ {}", "Waiting...".yellow());
-        std::io::stdout().lock().flush().unwrap();
-
-        let t2parse;
-        let t2run;
-
-        match input {
-            $(
-                i if i.trim() == format!("{:02}a", iter.next().unwrap()) => {
-                    let timer = ::std::time::Instant::now();
-                    let parsed = runner!(parse $day);
-                    t2parse = timer.elapsed();
-
-                    let timer = ::std::time::Instant::now();
-                    let output = runner!(run $day 1 parsed);
-                    t2run = timer.elapsed();
-
-                    print!("\x1b[1A╰─ ");
-                    println!("{} - {}", stringify!($day).blue(), "Level 1".magenta());
-                    println!("\toutput:\t{}", output.to_string().cyan());
-                },
-                i if i.trim() == format!("{:02}b", iter.next().unwrap()) => {
-                    let timer = ::std::time::Instant::now();
-                    let parsed = runner!(parse $day);
-                    t2parse = timer.elapsed();
-
-                    let timer = ::std::time::Instant::now();
-                    let output = runner!(run $day 2 parsed);
-                    t2run = timer.elapsed();
-
-                    print!("\x1b[1A╰─ ");
-                    println!("{} - {}", stringify!($day).blue(), "Level 2".magenta());
-                    println!("\toutput:\t{}", output.to_string().cyan());
-                },
-            )*
-            _ => {
-                println!("\x1b[1A╰─ {}", "Invalid day".red());
-                ::std::process::exit(1);
-            },
-        }
+#[cfg(feature = "memory")]
+#[global_allocator]
+static ALLOCATOR: libadvent::memory::Counting = libadvent::memory::Counting;
 
-        println!("\tparse:\t{}", colorize(t2parse, 4, 8));
-        println!("\trun:\t{}", colorize(t2run, 75, 200));
-        println!("\ttotal:\t{}", colorize(t2parse + t2run, 80, 210));
-    };
-}
+// allocations are only counted with the memory feature
+const MEMORY: bool = cfg!(feature = "memory");
 
-fn colorize(time: Duration, a: u64, b: u64) -> String {
-    let disp = format!("{time:?}");
+fn colorize(time: Duration, (a, b): (u64, u64), width: usize) -> String {
+    let disp = format!("{:<width$}", format!("{time:?}"));
 
     if time < Duration::from_millis(a) {
         format!("{}", disp.green())
@@ -107,113 +57,590 @@ fn colorize(time: Duration, a: u64, b: u64) -> String {
     }
 }
 
-fn input(file: &'static str) -> String {
-    let mut file = OpenOptions::new()
-        .read(true)
-        .open(file)
-        .expect("Could not open file");
+fn size(bytes: u64) -> String {
+    let mut size = bytes as f64;
+
+    for unit in ["B", "KiB", "MiB", "GiB"] {
+        if size < 1024.0 {
+            return if unit == "B" {
+                format!("{bytes} B")
+            } else {
+                format!("{size:.1} {unit}")
+            };
+        }
+
+        size /= 1024.0;
+    }
+
+    format!("{size:.1} TiB")
+}
+
+fn memory(memory: Memory) -> String {
+    format!(
+        "{} allocs, {} allocated, {} peak",
+        memory.allocs,
+        size(memory.bytes),
+        size(memory.peak)
+    )
+}
+
+// every year keeps its own inputs, answers and examples
+pub fn dataset() -> PathBuf {
+    config().dataset.join(config().year.to_string())
+}
 
+fn input(file: &Path) -> io::Result<String> {
+    let mut file = OpenOptions::new().read(true).open(file)?;
     let mut input = String::new();
 
-    file.read_to_string(&mut input)
-        .expect("Could not read file");
+    file.read_to_string(&mut input)?;
 
-    input.trim().to_string()
+    Ok(input.trim().to_string())
 }
 
-fn fetch() {
-    // try to open .token.txt
-    let mut file = OpenOptions::new()
-        .read(true)
-        .write(true)
-        .create(true)
-        .open(concat!(env!("CARGO_MANIFEST_DIR"), "/../.token.txt"))
-        .expect("Could not open .token.txt");
+fn key(day: usize, level: u8) -> String {
+    Target::Day(day, Some(level)).to_string()
+}
 
-    // check if file empty
-    let mut token = String::new();
+fn report(day: usize, name: &str, level: u8, run: Run, status: &Status) {
+    if output::machine() {
+        return output::emit(&Record::new(day, level, name, &Ok(run), Some(status)));
+    }
 
-    file.read_to_string(&mut token)
-        .expect("Could not read .token.txt");
+    println!("{} - {}", name.blue(), format!("Level {level}").magenta());
+    println!("\toutput:\t{}", run.output.to_string().cyan());
+    println!("\tstatus:\t{status}");
 
-    if token.is_empty() {
-        println!("\x1b[1A├─ Enter your token");
-        print!("╰─ ");
+    let thresholds = config().thresholds;
+    println!("\tparse:\t{}", colorize(run.parse, thresholds.parse, 0));
+    println!("\trun:\t{}", colorize(run.run, thresholds.run, 0));
+    println!(
+        "\ttotal:\t{}",
+        colorize(run.parse + run.run, thresholds.total, 0)
+    );
 
-        std::io::stdout().lock().flush().unwrap();
-        std::io::stdin().read_line(&mut token).unwrap();
+    if MEMORY {
+        println!("\theap:\tparse {}", memory(run.parse_memory).cyan());
+        println!("\t\trun   {}", memory(run.run_memory).cyan());
+    }
+}
 
-        file.write_all(token.as_bytes())
-            .expect("Could not write to .token.txt");
+fn failed(day: usize, name: &str, level: u8, e: io::Error) {
+    if output::machine() {
+        output::emit(&Record::new(day, level, name, &Err(e), None));
+    } else {
+        eprintln!("{}", format!("{name} level {level}: {e}").red());
+    }
+}
 
-        println!(
-            "\x1b[2A╰─ {}     \n{}  \n\x1b[2A",
-            "Fetching...".yellow(),
-            " ".repeat(token.len())
+fn read_input(day: usize, file: Option<&Path>) -> io::Result<String> {
+    let path = match file {
+        Some(file) => file.to_path_buf(),
+        None => dataset().join(format!("day{day:02}.txt")),
+    };
+
+    input(&path).map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))
+}
+
+// the selected year's days, none for a year without a module
+fn registered() -> &'static [Entry] {
+    solutions::days(config().year).unwrap_or_default()
+}
+
+fn lookup(day: usize) -> Option<&'static Entry> {
+    solutions::get(config().year, day)
+}
+
+// jobs only ever contain registered days
+fn entry(day: usize) -> &'static Entry {
+    lookup(day).expect("day is registered")
+}
+
+fn solve_input(day: usize, level: u8, input: &str, overrides: &Overrides) -> io::Result<Run> {
+    worker::solve(day, level, input, overrides)
+}
+
+fn solve(day: usize, level: u8, file: Option<&Path>, overrides: &Overrides) -> io::Result<Run> {
+    solve_input(day, level, &read_input(day, file)?, overrides)
+}
+
+// the fastest of --repeat runs, failing if --check-deterministic saw more than one answer
+fn solve_repeated(
+    day: usize,
+    level: u8,
+    input: &str,
+    overrides: &Overrides,
+    schedule: Schedule,
+) -> io::Result<Run> {
+    let mut best: Option<Run> = None;
+    let mut seen: Vec<Answer> = vec![];
+
+    for _ in 0..schedule.repeat {
+        let run = solve_input(day, level, input, overrides)?;
+
+        if !seen.contains(&run.output) {
+            seen.push(run.output.clone());
+        }
+
+        if best
+            .as_ref()
+            .is_none_or(|best| run.parse + run.run < best.parse + best.run)
+        {
+            best = Some(run);
+        }
+    }
+
+    if schedule.check_deterministic && seen.len() > 1 {
+        let seen = seen.iter().map(Answer::to_string).collect::<Vec<_>>();
+
+        return Err(io::Error::other(format!(
+            "not deterministic, {} runs gave {}",
+            schedule.repeat,
+            seen.join(" / ")
+        )));
+    }
+
+    Ok(best.expect("repeat is at least 1"))
+}
+
+fn jobs(target: Target) -> Option<Vec<(usize, u8)>> {
+    match target {
+        Target::All if solutions::days(config().year).is_none() => {
+            let years = solutions::YEARS.iter().map(|(year, _)| year.to_string());
+            eprintln!(
+                "{}",
+                format!(
+                    "Nothing solved for {}, only for {}",
+                    config().year,
+                    years.collect::<Vec<_>>().join(", ")
+                )
+                .red()
+            );
+            None
+        }
+        Target::All => Some(
+            registered()
+                .iter()
+                .flat_map(|entry| [(entry.day, 1), (entry.day, 2)])
+                .collect(),
+        ),
+        Target::Day(day, _) if lookup(day).is_none() => {
+            eprintln!("{}", format!("Day {day:02} is not solved yet").red());
+            None
+        }
+        Target::Day(day, Some(level)) => Some(vec![(day, level)]),
+        Target::Day(day, None) => Some(vec![(day, 1), (day, 2)]),
+    }
+}
+
+fn load_answers() -> Option<Answers> {
+    match Answers::load() {
+        Ok(answers) => Some(answers),
+        Err(e) => {
+            eprintln!("{}", format!("Could not read answers: {e}").red());
+            None
+        }
+    }
+}
+
+fn table(jobs: Vec<(usize, u8)>, answers: &Answers, schedule: Schedule) -> ExitCode {
+    let mut code = ExitCode::SUCCESS;
+    let mut table = Table::new();
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..(schedule.jobs as usize).min(jobs.len()) {
+            let (tx, next, jobs) = (tx.clone(), &next, &jobs);
+
+            scope.spawn(move || {
+                while let Some(&(day, level)) = jobs.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let result = read_input(day, None)
+                        .and_then(|input| solve_repeated(day, level, &input, &[], schedule));
+
+                    if tx.send((day, level, result)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        drop(tx);
+
+        // rows still come out in order, each as soon as everything before it is done
+        let mut done = BTreeMap::new();
+        let mut printed = 0;
+
+        for (day, level, result) in rx {
+            done.insert((day, level), result);
+
+            while let Some(&(day, level)) = jobs.get(printed) {
+                let Some(result) = done.remove(&(day, level)) else {
+                    break;
+                };
+
+                let status = result
+                    .as_ref()
+                    .ok()
+                    .map(|run| answers.check(&key(day, level), &run.output));
+
+                if result.is_err() || matches!(status, Some(Status::Wrong(_))) {
+                    code = ExitCode::FAILURE;
+                }
+
+                table.row(day, level, &result, status.as_ref());
+                printed += 1;
+            }
+        }
+    });
+
+    table.finish();
+    code
+}
+
+// the --input files, each with what to call it; stdin can only be read once
+fn load_inputs(files: &[PathBuf]) -> io::Result<Vec<(String, String)>> {
+    let stdin = Path::new("-");
+
+    if files.iter().filter(|file| *file == stdin).count() > 1 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "stdin can only be one of the inputs",
+        ));
+    }
+
+    files
+        .iter()
+        .map(|file| {
+            if file == stdin {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+
+                Ok(("stdin".to_string(), input.trim().to_string()))
+            } else {
+                let input = input(file)
+                    .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", file.display())))?;
+
+                Ok((file.display().to_string(), input))
+            }
+        })
+        .collect()
+}
+
+fn run(
+    target: Target,
+    files: &[PathBuf],
+    accept: bool,
+    overrides: &Overrides,
+    schedule: Schedule,
+) -> ExitCode {
+    let Some(jobs) = jobs(target) else {
+        return ExitCode::FAILURE;
+    };
+
+    if target == Target::All && !files.is_empty() {
+        eprintln!("{}", "--input needs a single day".red());
+        return ExitCode::FAILURE;
+    }
+
+    if target == Target::All && !overrides.is_empty() {
+        eprintln!("{}", "--param needs a single day".red());
+        return ExitCode::FAILURE;
+    }
+
+    // stored answers are for our own input, not whatever --input points at
+    if accept && (!files.is_empty() || !overrides.is_empty()) {
+        eprintln!(
+            "{}",
+            "--accept only works with the dataset input and default parameters".red()
         );
+        return ExitCode::FAILURE;
     }
 
-    let token = token.trim();
+    let inputs = match load_inputs(files) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("{}", format!("Could not read the input: {e}").red());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let Some(mut answers) = load_answers() else {
+        return ExitCode::FAILURE;
+    };
+
+    if target == Target::All && !accept {
+        return table(jobs, &answers, schedule);
+    }
+
+    let mut code = ExitCode::SUCCESS;
+
+    for (day, level) in jobs {
+        let name = entry(day).name;
+
+        // every input gets its own report, so answers for different accounts can be compared
+        if !inputs.is_empty() {
+            for (label, input) in &inputs {
+                let name = format!("{name} {label}");
+
+                match solve_repeated(day, level, input, overrides, schedule) {
+                    Ok(run) => report(day, &name, level, run, &Status::Unknown),
+                    Err(e) => {
+                        failed(day, &name, level, e);
+                        code = ExitCode::FAILURE;
+                    }
+                }
+            }
 
-    for day in 1..=25 {
-        // if file exists, skip
-        if Path::new(&format!(
-            "{}/../dataset/day{:02}.txt",
-            env!("CARGO_MANIFEST_DIR"),
-            day
-        ))
-        .exists()
-        {
-            println!("\x1b[1A╰─ {}", format!("Skipping Day {day:02}...").green());
             continue;
         }
 
-        // attempt to fetch the token using reqwest
-        println!("\x1b[1A╰─ {}", format!("Fetching Day {day:02}...").yellow());
+        let result = read_input(day, None)
+            .and_then(|input| solve_repeated(day, level, &input, overrides, schedule));
 
-        let url = format!("https://adventofcode.com/2024/day/{}/input", day);
-        let cookie = format!("session={}", token);
+        match result {
+            Ok(run) if !overrides.is_empty() => report(day, name, level, run, &Status::Unknown),
+            Ok(run) => {
+                if accept {
+                    answers.set(key(day, level), &run.output);
+                }
 
-        let client = reqwest::blocking::Client::new();
+                let status = answers.check(&key(day, level), &run.output);
+                report(day, name, level, run, &status);
+            }
+            Err(e) => {
+                failed(day, name, level, e);
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
 
-        let response = client
-            .get(&url)
-            .header("cookie", cookie)
-            .send()
-            .expect("Failed to fetch input");
+    if accept {
+        if let Err(e) = answers.save() {
+            eprintln!("{}", format!("Could not save answers: {e}").red());
+            code = ExitCode::FAILURE;
+        }
+    }
+
+    code
+}
 
-        match response.status() {
-            StatusCode::NOT_FOUND => break,
-            StatusCode::INTERNAL_SERVER_ERROR => {
-                println!("\x1b[2A╰─ {}", "Invalid Token".red());
-                break;
+// every example for the target, or only dayNN.example{which}.txt
+fn examples(
+    target: Target,
+    which: Option<String>,
+    overrides: &Overrides,
+    schedule: Schedule,
+) -> ExitCode {
+    let Some(jobs) = jobs(target) else {
+        return ExitCode::FAILURE;
+    };
+
+    let mut code = ExitCode::SUCCESS;
+    let mut ran = 0;
+
+    for (day, level) in jobs {
+        let examples = match examples::load(day) {
+            Ok(examples) => examples,
+            Err(e) => {
+                eprintln!("{}", format!("Could not read examples: {e}").red());
+                return ExitCode::FAILURE;
+            }
+        };
+
+        for example in examples {
+            if !example.covers(level)
+                || which
+                    .as_ref()
+                    .is_some_and(|k| example.name != format!("example{k}"))
+            {
+                continue;
+            }
+
+            ran += 1;
+
+            // --param wins over whatever examples.toml says
+            let params = example.params[level as usize - 1]
+                .iter()
+                .chain(overrides)
+                .cloned()
+                .collect::<Vec<_>>();
+            let name = format!("{} {}", entry(day).name, example.name);
+
+            let result = read_input(day, Some(&example.path))
+                .and_then(|input| solve_repeated(day, level, &input, &params, schedule));
+
+            match result {
+                Ok(run) => {
+                    let status = answers::status(
+                        example.expected[level as usize - 1].as_deref(),
+                        &run.output,
+                    );
+
+                    if matches!(status, Status::Wrong(_)) {
+                        code = ExitCode::FAILURE;
+                    }
+
+                    report(day, &name, level, run, &status);
+                }
+                Err(e) => {
+                    failed(day, &name, level, e);
+                    code = ExitCode::FAILURE;
+                }
             }
-            _ => {}
         }
+    }
+
+    if ran == 0 {
+        eprintln!("{}", format!("No examples for {target}").yellow());
+    }
 
-        let mut file = OpenOptions::new()
-            .write(true)
-            .create(true)
-            .truncate(true)
-            .open(format!(
-                "{}/../dataset/day{:02}.txt",
-                env!("CARGO_MANIFEST_DIR"),
-                day
-            ))
-            .expect("Could not open file");
+    code
+}
 
-        file.write_all(&response.bytes().expect("Failed to read response"))
-            .unwrap();
+fn list() -> ExitCode {
+    for entry in registered() {
+        let input = dataset().join(format!("day{:02}.txt", entry.day)).exists();
 
-        file.flush().unwrap();
+        println!(
+            "{}\t{}\t{}",
+            entry.name.blue(),
+            if input {
+                "input".green()
+            } else {
+                "no input".yellow()
+            },
+            entry.params.join(", ").magenta(),
+        );
     }
 
-    println!("\x1b[1A╰─ {}", "Fetched               ".green());
+    ExitCode::SUCCESS
 }
 
-fn main() {
-    runner!(
-        day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-        day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-    );
+// only levels with a recorded answer are checked
+fn verify(target: Target, schedule: Schedule) -> ExitCode {
+    let (Some(jobs), Some(answers)) = (jobs(target), load_answers()) else {
+        return ExitCode::FAILURE;
+    };
+
+    let jobs = jobs
+        .into_iter()
+        .filter(|(day, level)| answers.get(&key(*day, *level)).is_some())
+        .collect::<Vec<_>>();
+
+    if jobs.is_empty() {
+        eprintln!("{}", "No recorded answers to verify".yellow());
+        return ExitCode::SUCCESS;
+    }
+
+    table(jobs, &answers, schedule)
+}
+
+fn menu() -> ExitCode {
+    println!("╭─ Pick a day");
+    println!("│");
+    println!("├─ Format: DD[a|b]");
+    println!("├─ Example: 01a");
+    println!("├─ Or enter \"fetch\" to fetch all inputs");
+    println!("│");
+    print!("\n\n\n\n\n\x1b[5A╰─ ");
+
+    let mut choice = String::new();
+    io::stdout().lock().flush().unwrap();
+    io::stdin().read_line(&mut choice).unwrap();
+
+    if choice.trim() == "fetch" {
+        println!("\x1b[1A╰─ {}", "Fetching...".yellow());
+        return fetch::fetch(&ClientOpts::default(), true);
+    }
+
+    let Ok(Target::Day(day, Some(level))) = choice.parse() else {
+        println!("\x1b[1A╰─ {}", "Invalid day".red());
+        return ExitCode::FAILURE;
+    };
+
+    if lookup(day).is_none() {
+        println!("\x1b[1A╰─ {}", "Invalid day".red());
+        return ExitCode::FAILURE;
+    }
+
+    println!("\x1b[1A╰─ {}", "Waiting...".yellow());
+    io::stdout().lock().flush().unwrap();
+
+    let run = match solve(day, level, None, &[]) {
+        Ok(run) => run,
+        Err(e) => {
+            println!(
+                "\x1b[1A╰─ {}",
+                format!("{} level {level}: {e}", entry(day).name).red()
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+    let status = Answers::load()
+        .map(|answers| answers.check(&key(day, level), &run.output))
+        .unwrap_or(Status::Unknown);
+
+    print!("\x1b[1A╰─ ");
+    report(day, entry(day).name, level, run, &status);
+
+    ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse_checked();
+
+    // rayon reads it when a pool starts, in the workers as well as for bench
+    if let Some(threads) = cli.threads {
+        env::set_var("RAYON_NUM_THREADS", threads.to_string());
+    }
+
+    if let Err(e) = config::init(&cli) {
+        eprintln!("{}", format!("Could not read the config: {e}").red());
+        return ExitCode::FAILURE;
+    }
+
+    match cli.command {
+        None => menu(),
+        Some(Command::Run {
+            target,
+            example: Some(which),
+            params,
+            schedule,
+            ..
+        }) => examples(target, which, &params, schedule),
+        Some(Command::Run {
+            target,
+            inputs,
+            accept,
+            example: None,
+            params,
+            schedule,
+        }) => run(target, &inputs, accept, &params, schedule),
+        Some(Command::Watch { target, example }) => watch::watch(target, example),
+        Some(Command::List) => list(),
+        Some(Command::Verify { target, schedule }) => verify(target, schedule),
+        Some(Command::Bench(opts)) => bench::bench(opts),
+        Some(Command::Fetch {
+            puzzle: Some(day),
+            html,
+            client,
+        }) => fetch::puzzle(day as usize, html.as_deref(), &client),
+        Some(Command::Fetch { client, .. }) => fetch::fetch(&client, false),
+        Some(Command::Submit {
+            target: Target::Day(day, Some(level)),
+            client,
+        }) => submit::submit(day, level, &client),
+        Some(Command::Submit { .. }) => {
+            eprintln!("{}", "submit needs a single level, like 05b".red());
+            ExitCode::FAILURE
+        }
+        Some(Command::Dot { day, out, input }) => {
+            dot::dot(day as usize, out.as_deref(), input.as_deref())
+        }
+        Some(Command::New { day, answers }) => scaffold::new(day as usize, answers),
+        Some(Command::Token(TokenAction::Check(client))) => token::check(&client),
+        Some(Command::Token(TokenAction::Clear)) => token::clear(),
+        Some(Command::Worker { day, level, params }) => worker::serve(day, level, &params),
+    }
 }
@@ -0,0 +1,230 @@
+use std::{
+    io::{self, Write},
+    process,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use libadvent::Answer;
+use serde::{Deserialize, Serialize};
+
+use crate::{answers::Status, bench::Stats, cli::Format, config::config, Run, MEMORY};
+
+// something --format json and csv can print, one per line
+pub trait Row: Serialize {
+    const HEADER: &'static [&'static str];
+
+    // in the same order as HEADER
+    fn fields(&self) -> Vec<String>;
+}
+
+// a level's result, from run, verify or an example
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u32,
+    pub day: usize,
+    pub level: u8,
+    pub name: String,
+    pub answer: Option<Answer>,
+    pub parse_ns: Option<u64>,
+    pub run_ns: Option<u64>,
+    pub total_ns: Option<u64>,
+    // only with the memory feature
+    pub parse_allocs: Option<u64>,
+    pub parse_bytes: Option<u64>,
+    pub parse_peak: Option<u64>,
+    pub run_allocs: Option<u64>,
+    pub run_bytes: Option<u64>,
+    pub run_peak: Option<u64>,
+    // correct, wrong or unknown; none if the level failed
+    pub status: Option<String>,
+    pub expected: Option<String>,
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn new(
+        day: usize,
+        level: u8,
+        name: &str,
+        result: &io::Result<Run>,
+        status: Option<&Status>,
+    ) -> Self {
+        let run = result.as_ref().ok();
+        let ns = |d: std::time::Duration| d.as_nanos() as u64;
+        let memory = run.filter(|_| MEMORY);
+
+        Self {
+            year: config().year,
+            day,
+            level,
+            name: name.to_string(),
+            answer: run.map(|run| run.output.clone()),
+            parse_ns: run.map(|run| ns(run.parse)),
+            run_ns: run.map(|run| ns(run.run)),
+            total_ns: run.map(|run| ns(run.parse + run.run)),
+            parse_allocs: memory.map(|run| run.parse_memory.allocs),
+            parse_bytes: memory.map(|run| run.parse_memory.bytes),
+            parse_peak: memory.map(|run| run.parse_memory.peak),
+            run_allocs: memory.map(|run| run.run_memory.allocs),
+            run_bytes: memory.map(|run| run.run_memory.bytes),
+            run_peak: memory.map(|run| run.run_memory.peak),
+            status: run.map(|_| status.unwrap_or(&Status::Unknown).name().to_string()),
+            expected: match status {
+                Some(Status::Wrong(expected)) => Some(expected.clone()),
+                _ => None,
+            },
+            error: result.as_ref().err().map(io::Error::to_string),
+        }
+    }
+}
+
+fn field<T: ToString>(value: &Option<T>) -> String {
+    value.as_ref().map(T::to_string).unwrap_or_default()
+}
+
+impl Row for Record {
+    const HEADER: &'static [&'static str] = &[
+        "year",
+        "day",
+        "level",
+        "name",
+        "answer",
+        "parse_ns",
+        "run_ns",
+        "total_ns",
+        "parse_allocs",
+        "parse_bytes",
+        "parse_peak",
+        "run_allocs",
+        "run_bytes",
+        "run_peak",
+        "status",
+        "expected",
+        "error",
+    ];
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.year.to_string(),
+            self.day.to_string(),
+            self.level.to_string(),
+            self.name.clone(),
+            field(&self.answer),
+            field(&self.parse_ns),
+            field(&self.run_ns),
+            field(&self.total_ns),
+            field(&self.parse_allocs),
+            field(&self.parse_bytes),
+            field(&self.parse_peak),
+            field(&self.run_allocs),
+            field(&self.run_bytes),
+            field(&self.run_peak),
+            field(&self.status),
+            field(&self.expected),
+            field(&self.error),
+        ]
+    }
+}
+
+// a benchmarked level, stats in nanoseconds and changes in percent against the baseline
+#[derive(Debug, Serialize)]
+pub struct BenchRecord {
+    pub year: u32,
+    pub day: usize,
+    pub level: u8,
+    pub name: String,
+    pub runs: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+    pub parse_change: Option<f64>,
+    pub solve_change: Option<f64>,
+    pub regression: bool,
+}
+
+impl Row for BenchRecord {
+    const HEADER: &'static [&'static str] = &[
+        "year",
+        "day",
+        "level",
+        "name",
+        "runs",
+        "parse_min",
+        "parse_median",
+        "parse_mean",
+        "parse_stddev",
+        "solve_min",
+        "solve_median",
+        "solve_mean",
+        "solve_stddev",
+        "parse_change",
+        "solve_change",
+        "regression",
+    ];
+
+    fn fields(&self) -> Vec<String> {
+        let stats = |s: &Stats| [s.min, s.median, s.mean, s.stddev].map(|x| x.to_string());
+
+        [
+            self.year.to_string(),
+            self.day.to_string(),
+            self.level.to_string(),
+            self.name.clone(),
+            self.runs.to_string(),
+        ]
+        .into_iter()
+        .chain(stats(&self.parse))
+        .chain(stats(&self.solve))
+        .chain([
+            field(&self.parse_change),
+            field(&self.solve_change),
+            self.regression.to_string(),
+        ])
+        .collect()
+    }
+}
+
+fn csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+// a run only ever prints one kind of row
+static HEADER: AtomicBool = AtomicBool::new(false);
+
+pub fn machine() -> bool {
+    config().format != Format::Text
+}
+
+// `--format csv | head` closes the pipe early, which only means nobody wants the rest
+fn write(lines: &[String]) {
+    let mut stdout = io::stdout().lock();
+
+    for line in lines {
+        match writeln!(stdout, "{line}") {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => process::exit(0),
+            Err(e) => panic!("failed printing to stdout: {e}"),
+        }
+    }
+}
+
+pub fn emit<R: Row>(row: &R) {
+    match config().format {
+        Format::Text => unreachable!("text is printed by whoever has the result"),
+        Format::Json => write(&[serde_json::to_string(row).unwrap()]),
+        Format::Csv => {
+            let mut lines = vec![];
+
+            if !HEADER.swap(true, Ordering::Relaxed) {
+                lines.push(R::HEADER.join(","));
+            }
+
+            let fields = row.fields().iter().map(|f| csv(f)).collect::<Vec<_>>();
+            lines.push(fields.join(","));
+            write(&lines);
+        }
+    }
+}
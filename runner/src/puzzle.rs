@@ -0,0 +1,317 @@
+use std::{fs, io, path::Path};
+
+// just enough html for the puzzle pages, which are a handful of well-formed tags
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(&'a str),
+}
+
+fn tokens(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+
+        let Some(end) = rest[start..].find('>') else {
+            tokens.push(Token::Text(&rest[start..]));
+            break;
+        };
+
+        let tag = rest[start + 1..start + end].trim_end_matches('/');
+        rest = &rest[start + end + 1..];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim()));
+        } else if !tag.starts_with('!') {
+            let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+            tokens.push(Token::Open(name, attrs));
+        }
+    }
+
+    tokens
+}
+
+fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn attr<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let (_, rest) = attrs.split_once(&format!("{name}="))?;
+    let quote = rest.chars().next()?;
+    let rest = &rest[1..];
+
+    rest.find(quote).map(|end| &rest[..end])
+}
+
+// only the <article>s, everything else on the page is navigation
+fn articles<'a>(tokens: &[Token<'a>]) -> Vec<Token<'a>> {
+    let mut out = vec![];
+    let mut depth = 0;
+
+    for token in tokens {
+        match token {
+            Token::Open("article", _) => depth += 1,
+            Token::Close("article") => {
+                depth -= 1;
+                out.push(Token::Close("article"));
+            }
+            token if depth > 0 => out.push(*token),
+            _ => {}
+        }
+    }
+
+    out
+}
+
+// ends the current block with exactly one blank line
+fn paragraph(out: &mut String) {
+    while !out.is_empty() && !out.ends_with("\n\n") {
+        out.push('\n');
+    }
+}
+
+// the puzzle description as markdown, both parts if the page has them
+pub fn markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut pre = false;
+    let mut code = false;
+    let mut links = vec![];
+
+    for token in articles(&tokens(html)) {
+        match token {
+            Token::Open("h2", _) => out.push_str("## "),
+            Token::Close("h2" | "p" | "ul" | "article") => paragraph(&mut out),
+            Token::Open("pre", _) => {
+                pre = true;
+                out.push_str("```\n");
+            }
+            Token::Close("pre") => {
+                pre = false;
+
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+
+                out.push_str("```");
+                paragraph(&mut out);
+            }
+            Token::Open("code", _) | Token::Close("code") if !pre => {
+                code = matches!(token, Token::Open(..));
+                out.push('`');
+            }
+            // emphasis can't be shown inside code
+            Token::Open("em", _) | Token::Close("em") if !pre && !code => out.push('*'),
+            Token::Open("a", attrs) => {
+                links.push(attr(attrs, "href").unwrap_or("").to_string());
+                out.push('[');
+            }
+            Token::Close("a") => out.push_str(&format!("]({})", links.pop().unwrap_or_default())),
+            Token::Open("li", _) => out.push_str("- "),
+            Token::Close("li") => out.push('\n'),
+            Token::Text(text) if pre => out.push_str(&unescape(text)),
+            Token::Text(text) => {
+                let text = unescape(text).replace('\n', " ");
+
+                // the gaps between block tags are only there for the html
+                if !(text.trim().is_empty() && (out.is_empty() || out.ends_with('\n'))) {
+                    out.push_str(&text);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    out.trim_end().to_string() + "\n"
+}
+
+// the contents of every <pre><code> block, the puzzle examples are among these
+pub fn examples(html: &str) -> Vec<String> {
+    let mut blocks: Vec<String> = vec![];
+    let mut block = None;
+
+    for token in articles(&tokens(html)) {
+        match token {
+            Token::Open("pre", _) => block = Some(String::new()),
+            Token::Close("pre") => {
+                let text = block.take().unwrap_or_default().trim_end().to_string();
+
+                if !text.is_empty() && !blocks.contains(&text) {
+                    blocks.push(text);
+                }
+            }
+            Token::Text(text) => {
+                if let Some(block) = &mut block {
+                    block.push_str(&unescape(text));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    blocks
+}
+
+// writes dayNN.md, and every example block that isn't already in a dayNN.exampleK.txt
+// empty example files (like the one `new` leaves) are filled before new ones are added
+// returns the new example files
+pub fn save(dir: &Path, day: usize, html: &str) -> io::Result<Vec<String>> {
+    fs::create_dir_all(dir)?;
+    fs::write(dir.join(format!("day{day:02}.md")), markdown(html))?;
+
+    let path = |k: usize| dir.join(format!("day{day:02}.example{k}.txt"));
+    let read = |p: &Path| fs::read_to_string(p).map(|s| s.trim_end().to_string());
+
+    let mut existing = vec![];
+    let mut empty = vec![];
+    let mut k = 1;
+
+    while path(k).exists() {
+        match read(&path(k))? {
+            block if block.trim().is_empty() => empty.push(k),
+            block => existing.push(block),
+        }
+
+        k += 1;
+    }
+
+    // the empty slots in order, then past the last file
+    let mut slots = empty.into_iter().chain(k..);
+    let mut written = vec![];
+
+    for block in examples(html) {
+        if existing.contains(&block) {
+            continue;
+        }
+
+        let k = slots.next().unwrap();
+        fs::write(path(k), format!("{block}\n"))?;
+        written.push(format!("day{day:02}.example{k}.txt"));
+        existing.push(block);
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, path::PathBuf, process};
+
+    use super::*;
+
+    const PART1: &str = include_str!("../tests/fixtures/puzzle/day05-part1.html");
+    const PART2: &str = include_str!("../tests/fixtures/puzzle/day05-part2.html");
+
+    const MARKDOWN: &str = "\
+## --- Day 5: Print Queue ---
+
+The notation `X|Y` means that if both page number `X` and page number `Y` are to be produced, \
+page number `X` *must* be printed at some point before page number `Y`.
+
+For example:
+
+```
+47|53
+97|13
+
+75,47,61,53,29
+97,61,53,29,13
+```
+
+Some things to check:
+
+- Pages & rules, see [this](https://en.wikipedia.org/wiki/Topological_sorting).
+- The middle page of `75,47,61` is `47`.
+
+What do you get if you add up the middle page number from those correctly-ordered updates?
+";
+
+    const RULES: &str = "47|53\n97|13\n\n75,47,61,53,29\n97,61,53,29,13";
+    const UPDATES: &str = "97|13\n\n75,47,61,53,29\n97,61,53,29,13";
+
+    // a fresh directory per test, they run in parallel
+    fn dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("advent-puzzle-{}-{name}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn read(dir: &Path, file: &str) -> String {
+        fs::read_to_string(dir.join(file)).unwrap()
+    }
+
+    #[test]
+    fn part_one() {
+        assert_eq!(markdown(PART1), MARKDOWN);
+        assert_eq!(examples(PART1), [RULES]);
+    }
+
+    #[test]
+    fn part_two() {
+        let md = markdown(PART2);
+
+        // the answer paragraph and the form sit outside the articles
+        assert!(md.starts_with(MARKDOWN));
+        assert!(!md.contains("Your puzzle answer"));
+        assert!(!md.contains("Answer:"));
+        assert!(
+            md.contains("\n## --- Part Two ---\n\nFor each of the *incorrectly-ordered updates*, ")
+        );
+        assert!(md.contains("```\n  x < y\n```\n"));
+        assert!(md.ends_with("after correctly ordering just those updates?*\n"));
+
+        assert_eq!(examples(PART2), [RULES, UPDATES, "  x < y"]);
+    }
+
+    #[test]
+    fn refetch() {
+        let dir = dir("refetch");
+
+        assert_eq!(save(&dir, 5, PART1).unwrap(), ["day05.example1.txt"]);
+        assert_eq!(read(&dir, "day05.md"), MARKDOWN);
+        assert_eq!(read(&dir, "day05.example1.txt"), format!("{RULES}\n"));
+
+        assert!(save(&dir, 5, PART1).unwrap().is_empty());
+        assert_eq!(
+            save(&dir, 5, PART2).unwrap(),
+            ["day05.example2.txt", "day05.example3.txt"]
+        );
+        assert_eq!(read(&dir, "day05.md"), markdown(PART2));
+        assert_eq!(read(&dir, "day05.example1.txt"), format!("{RULES}\n"));
+        assert_eq!(read(&dir, "day05.example2.txt"), format!("{UPDATES}\n"));
+        assert!(save(&dir, 5, PART2).unwrap().is_empty());
+        assert!(!dir.join("day05.example4.txt").exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn fills_empty_examples() {
+        let dir = dir("empty");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day05.example1.txt"), "").unwrap();
+        fs::write(dir.join("day05.example2.txt"), format!("{UPDATES}\n")).unwrap();
+
+        assert_eq!(
+            save(&dir, 5, PART2).unwrap(),
+            ["day05.example1.txt", "day05.example3.txt"]
+        );
+        assert_eq!(read(&dir, "day05.example1.txt"), format!("{RULES}\n"));
+        assert_eq!(read(&dir, "day05.example3.txt"), "  x < y\n");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
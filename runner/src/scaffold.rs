@@ -0,0 +1,341 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    process::ExitCode,
+};
+
+use colored::Colorize;
+
+use crate::{config::config, dataset};
+
+const WIDTH: usize = 100;
+
+// the idents in a `name!(...)` list, and where that list starts and ends in the source
+fn registered(source: &str, name: &str) -> Option<(Vec<String>, usize, usize)> {
+    let open = format!("{name}!(");
+    let start = match source.strip_prefix(&open) {
+        Some(_) => 0,
+        None => source.find(&format!("\n{open}"))? + 1,
+    };
+    let end = start + source[start..].find(");")? + 2;
+    let inner = &source[start + open.len()..end - 2];
+
+    let idents = inner
+        .split(',')
+        .map(str::trim)
+        .filter(|ident| !ident.is_empty())
+        .map(str::to_string)
+        .collect();
+
+    Some((idents, start, end))
+}
+
+// laid out the way rustfmt leaves it
+fn list(name: &str, idents: &[String]) -> String {
+    let line = format!("{name}!({});", idents.join(", "));
+
+    if line.len() <= WIDTH {
+        return line;
+    }
+
+    let mut out = format!("{name}!(\n");
+    let mut line = String::from("   ");
+
+    for ident in idents {
+        if line.len() + ident.len() + 2 > WIDTH {
+            out.push_str(line.trim_end());
+            out.push('\n');
+            line = String::from("   ");
+        }
+
+        line.push_str(&format!(" {ident},"));
+    }
+
+    out.push_str(&line);
+    out.push_str("\n);");
+    out
+}
+
+// adds the ident to the file's `name!(...)` list, keeping it sorted; false if it was already there
+fn register(path: &Path, name: &str, ident: &str) -> io::Result<bool> {
+    let source = fs::read_to_string(path)?;
+    let Some((mut idents, start, end)) = registered(&source, name) else {
+        return Err(io::Error::other(format!(
+            "no {name}!(...) list in {}",
+            path.display()
+        )));
+    };
+
+    if idents.iter().any(|i| i == ident) {
+        return Ok(false);
+    }
+
+    idents.push(ident.to_string());
+    idents.sort();
+
+    fs::write(
+        path,
+        format!(
+            "{}{}{}",
+            &source[..start],
+            list(name, &idents),
+            &source[end..]
+        ),
+    )?;
+
+    Ok(true)
+}
+
+// creates the file unless it exists; false if it did
+fn create(path: &Path, contents: &str) -> io::Result<bool> {
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(mut file) => file.write_all(contents.as_bytes()).map(|_| true),
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+// an empty [dayNN.example1] table for the expected answers; false if there already is one
+fn open_answers(name: &str) -> io::Result<bool> {
+    let path = dataset().join("examples.toml");
+    let table = format!("[{name}.example1]");
+    let existing = match fs::read_to_string(&path) {
+        Ok(s) => s,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+
+    if existing.lines().any(|line| line.trim() == table) {
+        return Ok(false);
+    }
+
+    let gap = if existing.is_empty() || existing.ends_with("\n\n") {
+        ""
+    } else if existing.ends_with('\n') {
+        "\n"
+    } else {
+        "\n\n"
+    };
+
+    fs::write(
+        path,
+        format!("{existing}{gap}{table}\n# a = \"\"\n# b = \"\"\n"),
+    )?;
+
+    Ok(true)
+}
+
+fn scaffold(day: usize, answers: bool) -> io::Result<()> {
+    let src = &config().solutions;
+    let year = format!("y{}", config().year);
+    let name = format!("day{day:02}");
+    let module = src.join(&year).join(format!("{name}.rs"));
+
+    // a year's first day also starts its module
+    fs::create_dir_all(src.join(&year))?;
+    fs::create_dir_all(dataset())?;
+
+    let template = fs::read_to_string(src.join("_template.rs"))?;
+    let steps = [
+        (
+            format!("{year}/mod.rs"),
+            create(&src.join(&year).join("mod.rs"), "solutions!();\n")?,
+        ),
+        (
+            format!("{year} in lib.rs"),
+            register(&src.join("lib.rs"), "years", &year)?,
+        ),
+        (format!("{year}/{name}.rs"), create(&module, &template)?),
+        (
+            format!("{name} in {year}/mod.rs"),
+            register(&src.join(&year).join("mod.rs"), "solutions", &name)?,
+        ),
+        (
+            format!("{name}.txt"),
+            create(&dataset().join(format!("{name}.txt")), "")?,
+        ),
+        (
+            format!("{name}.example1.txt"),
+            create(&dataset().join(format!("{name}.example1.txt")), "")?,
+        ),
+    ];
+
+    // the year's module only needs a mention when it's new
+    for (i, (what, done)) in steps.into_iter().enumerate() {
+        if done {
+            println!("{}", format!("Created {what}").green());
+        } else if i >= 2 {
+            println!("{}", format!("Kept {what}").yellow());
+        }
+    }
+
+    if answers {
+        if open_answers(&name)? {
+            println!(
+                "{}",
+                format!("Added [{name}.example1] to examples.toml").green()
+            );
+        } else {
+            println!(
+                "{}",
+                format!("Kept [{name}.example1] in examples.toml").yellow()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+pub fn new(day: usize, answers: bool) -> ExitCode {
+    match scaffold(day, answers) {
+        Ok(()) => {
+            println!(
+                "Rebuild to run it: cargo run -- --year {} run {day:02}",
+                config().year
+            );
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", format!("Day {day:02}: {e}").red());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, path::PathBuf, process};
+
+    use super::*;
+
+    const DAYS: &str = "\
+solutions!(
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25,
+);
+";
+
+    const LIB: &str = "\
+use libadvent::solution::{number, Entry};
+
+// one module per year, each with its own solutions!(...)
+macro_rules! years {
+    ($($year:ident),* $(,)?) => {};
+}
+
+years!(y2024);
+
+pub fn days() {}
+";
+
+    // a fresh file per test, they run in parallel
+    fn file(name: &str, contents: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("advent-scaffold-{}-{name}.rs", process::id()));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn days(skip: &str) -> Vec<String> {
+        (1..=25)
+            .map(|day| format!("day{day:02}"))
+            .filter(|day| day != skip)
+            .collect()
+    }
+
+    #[test]
+    fn finds_the_list() {
+        let (idents, start, end) = registered(LIB, "years").unwrap();
+
+        assert_eq!(idents, ["y2024"]);
+        assert_eq!(&LIB[start..end], "years!(y2024);");
+
+        let (idents, start, end) = registered(DAYS, "solutions").unwrap();
+
+        assert_eq!(idents, days(""));
+        assert_eq!((start, end), (0, DAYS.len() - 1));
+        assert_eq!(registered(LIB, "solutions"), None);
+    }
+
+    #[test]
+    fn layout() {
+        assert_eq!(list("years", &["y2024".to_string()]), "years!(y2024);");
+        assert_eq!(list("solutions", &[]), "solutions!();");
+        assert_eq!(list("solutions", &days("")) + "\n", DAYS);
+    }
+
+    #[test]
+    fn single_line() {
+        let path = file("single", LIB);
+
+        assert!(register(&path, "years", "y2023").unwrap());
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            LIB.replace("years!(y2024);", "years!(y2023, y2024);")
+        );
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn wrapped() {
+        // day14 is the first that no longer fits on the first line
+        let path = file("wrapped", &(list("solutions", &days("day14")) + "\n"));
+
+        assert!(register(&path, "solutions", "day14").unwrap());
+        assert_eq!(fs::read_to_string(&path).unwrap(), DAYS);
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn sorted() {
+        let path = file("sorted", "solutions!(day01, day05);\n");
+
+        assert!(register(&path, "solutions", "day03").unwrap());
+        assert!(register(&path, "solutions", "day09").unwrap());
+        assert!(register(&path, "solutions", "day00").unwrap());
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "solutions!(day00, day01, day03, day05, day09);\n"
+        );
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn rerun() {
+        let path = file("rerun", DAYS);
+
+        assert!(!register(&path, "solutions", "day07").unwrap());
+        assert_eq!(fs::read_to_string(&path).unwrap(), DAYS);
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn missing_list() {
+        let path = file("missing", "pub mod day01;\n");
+        let e = register(&path, "solutions", "day02").unwrap_err();
+
+        assert!(
+            e.to_string().starts_with("no solutions!(...) list in"),
+            "{e}"
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "pub mod day01;\n");
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn creates_once() {
+        let path = env::temp_dir().join(format!("advent-scaffold-{}-create.rs", process::id()));
+        let _ = fs::remove_file(&path);
+
+        assert!(create(&path, "first").unwrap());
+        assert!(!create(&path, "second").unwrap());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "first");
+
+        fs::remove_file(path).unwrap();
+    }
+}
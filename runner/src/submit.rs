@@ -0,0 +1,541 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    path::PathBuf,
+    process::ExitCode,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use colored::Colorize;
+use libadvent::Answer;
+use serde::{Deserialize, Serialize};
+
+use crate::{cli::ClientOpts, dataset, entry, fetch, key, load_answers, lookup, solve};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "{}", "correct".green()),
+            Self::TooHigh => write!(f, "{}", "wrong, too high".red()),
+            Self::TooLow => write!(f, "{}", "wrong, too low".red()),
+            Self::Wrong => write!(f, "{}", "wrong".red()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Response {
+    Judged(Verdict),
+    // answered too recently, nothing was checked
+    Wait,
+    // the level is already done (or level 1 isn't yet)
+    WrongLevel,
+    Unknown,
+}
+
+// the interesting part of the page is the first <article>, tags stripped
+fn article(html: &str) -> String {
+    let start = html.find("<article").unwrap_or(0);
+    let end = html[start..]
+        .find("</article>")
+        .map_or(html.len(), |end| start + end);
+
+    let mut text = String::new();
+    let mut tag = false;
+
+    for ch in html[start..end].chars() {
+        match ch {
+            '<' => tag = true,
+            '>' => tag = false,
+            ch if !tag => text.push(ch),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// "You have 4m 32s left to wait", "Please wait one minute", "please wait 5 minutes"
+fn cooldown(text: &str) -> Option<Duration> {
+    let left = text
+        .split_once("You have ")
+        .and_then(|(_, rest)| rest.split_once(" left to wait"));
+
+    if let Some((left, _)) = left {
+        let mut secs = 0;
+
+        for part in left.split_whitespace() {
+            let (n, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let n = n.parse::<u64>().ok()?;
+
+            secs += match unit {
+                "h" => n * 3600,
+                "m" => n * 60,
+                _ => n,
+            };
+        }
+
+        return Some(Duration::from_secs(secs));
+    }
+
+    let text = text.to_lowercase();
+    let (_, rest) = text.split_once("wait ")?;
+    let mut words = rest.split_whitespace();
+    let n = match words.next()? {
+        "one" | "a" => 1,
+        n => n.parse().ok()?,
+    };
+
+    words
+        .next()?
+        .starts_with("minute")
+        .then(|| Duration::from_secs(n * 60))
+}
+
+pub fn parse(html: &str) -> (Response, Option<Duration>) {
+    let text = article(html);
+
+    let response = if text.contains("That's the right answer") {
+        Response::Judged(Verdict::Correct)
+    } else if text.contains("That's not the right answer") {
+        Response::Judged(if text.contains("too high") {
+            Verdict::TooHigh
+        } else if text.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        })
+    } else if text.contains("You gave an answer too recently") {
+        Response::Wait
+    } else if text.contains("You don't seem to be solving the right level") {
+        Response::WrongLevel
+    } else {
+        Response::Unknown
+    };
+
+    (response, cooldown(&text))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub answer: Answer,
+    pub verdict: Verdict,
+    pub time: u64,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Level {
+    // unix time before which the site won't take another answer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_until: Option<u64>,
+    #[serde(default)]
+    pub submissions: Vec<Submission>,
+}
+
+impl Level {
+    // everything too low is below `low`, everything too high above `high`
+    pub fn bounds(&self) -> (Option<i128>, Option<i128>) {
+        let judged = |verdict| {
+            self.submissions
+                .iter()
+                .filter(move |s| s.verdict == verdict)
+                .filter_map(|s| s.answer.as_int())
+        };
+
+        (
+            judged(Verdict::TooLow).max(),
+            judged(Verdict::TooHigh).min(),
+        )
+    }
+
+    // why this answer shouldn't be sent, if it shouldn't
+    pub fn reject(&self, answer: &Answer) -> Option<String> {
+        if let Some(s) = self
+            .submissions
+            .iter()
+            .find(|s| s.answer.normalize() == answer.normalize())
+        {
+            return Some(format!(
+                "{answer} was already submitted, it was {}",
+                s.verdict
+            ));
+        }
+
+        if let Some(s) = self
+            .submissions
+            .iter()
+            .find(|s| s.verdict == Verdict::Correct)
+        {
+            return Some(format!("Already solved with {}", s.answer));
+        }
+
+        if let Some(wait) = self.wait_until.filter(|until| *until > now()) {
+            return Some(format!("Wait another {}s before submitting", wait - now()));
+        }
+
+        let n = answer.as_int()?;
+
+        match self.bounds() {
+            (Some(low), _) if n <= low => Some(format!("{answer} is too low, {low} already was")),
+            (_, Some(high)) if n >= high => {
+                Some(format!("{answer} is too high, {high} already was"))
+            }
+            _ => None,
+        }
+    }
+}
+
+// dataset/<year>/submissions.toml, keyed like the cli
+pub struct Log {
+    levels: BTreeMap<String, Level>,
+}
+
+fn path() -> PathBuf {
+    dataset().join("submissions.toml")
+}
+
+impl Log {
+    fn from_toml(s: &str) -> io::Result<Self> {
+        let levels = toml::from_str(s).map_err(io::Error::other)?;
+        Ok(Self { levels })
+    }
+
+    fn to_toml(&self) -> io::Result<String> {
+        toml::to_string(&self.levels).map_err(io::Error::other)
+    }
+
+    pub fn load() -> io::Result<Self> {
+        match fs::read_to_string(path()) {
+            Ok(s) => Self::from_toml(&s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self {
+                levels: BTreeMap::new(),
+            }),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn level(&mut self, key: String) -> &mut Level {
+        self.levels.entry(key).or_default()
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        fs::write(path(), self.to_toml()?)
+    }
+}
+
+pub fn submit(day: usize, level: u8, opts: &ClientOpts) -> ExitCode {
+    if lookup(day).is_none() {
+        eprintln!("{}", format!("Day {day:02} is not solved yet").red());
+        return ExitCode::FAILURE;
+    }
+
+    let Some(mut answers) = load_answers() else {
+        return ExitCode::FAILURE;
+    };
+
+    let mut log = match Log::load() {
+        Ok(log) => log,
+        Err(e) => {
+            eprintln!(
+                "{}",
+                format!("Could not read the submission log: {e}").red()
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let answer = match solve(day, level, None, &[]) {
+        Ok(run) => run.output,
+        Err(e) => {
+            eprintln!("{}", format!("Day {day:02} level {level}: {e}").red());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!(
+        "{} - {}",
+        entry(day).name.blue(),
+        format!("Level {level}").magenta()
+    );
+    println!("\tanswer:\t{}", answer.to_string().cyan());
+
+    let key = key(day, level);
+    let entry = log.level(key.clone());
+
+    if let Some(reason) = entry.reject(&answer) {
+        println!("\tstatus:\t{}", reason.yellow());
+        return ExitCode::FAILURE;
+    }
+
+    let mut client = match fetch::client(opts, false) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{}", e.to_string().red());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let html = match client.answer(day, level, &answer.to_string()) {
+        Ok(html) => html,
+        Err(e) => {
+            eprintln!("{}", e.to_string().red());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (response, wait) = parse(&html);
+    entry.wait_until = wait.map(|wait| now() + wait.as_secs());
+
+    let code = match response {
+        Response::Judged(verdict) => {
+            entry.submissions.push(Submission {
+                answer: answer.clone(),
+                verdict,
+                time: now(),
+            });
+
+            println!("\tstatus:\t{verdict}");
+
+            if verdict == Verdict::Correct {
+                answers.set(key, &answer);
+
+                if let Err(e) = answers.save() {
+                    eprintln!("{}", format!("Could not save answers: {e}").red());
+                }
+
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Response::Wait => {
+            let wait = wait.map_or("a while".to_string(), |w| format!("{}s", w.as_secs()));
+            println!("\tstatus:\t{}", format!("Too soon, wait {wait}").yellow());
+            ExitCode::FAILURE
+        }
+        Response::WrongLevel => {
+            println!(
+                "\tstatus:\t{}",
+                "Wrong level, already solved or level 1 is still open".yellow()
+            );
+            ExitCode::FAILURE
+        }
+        Response::Unknown => {
+            println!("\tstatus:\t{}", "Could not understand the response".red());
+            ExitCode::FAILURE
+        }
+    };
+
+    if let (Response::Judged(_), Some(wait)) = (response, wait) {
+        println!("\twait:\t{}s before the next answer", wait.as_secs());
+    }
+
+    if let Err(e) = log.save() {
+        eprintln!(
+            "{}",
+            format!("Could not save the submission log: {e}").red()
+        );
+        return ExitCode::FAILURE;
+    }
+
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submission(answer: impl Into<Answer>, verdict: Verdict) -> Submission {
+        Submission {
+            answer: answer.into(),
+            verdict,
+            time: 1733036400,
+        }
+    }
+
+    #[test]
+    fn log_round_trip() {
+        let mut log = Log {
+            levels: BTreeMap::new(),
+        };
+
+        log.level("01a".to_string()).submissions = vec![
+            submission(12, Verdict::TooLow),
+            submission(u64::MAX as i128 * 3, Verdict::TooHigh),
+            submission(1u128 << 64, Verdict::Correct),
+        ];
+        log.level("17a".to_string()).submissions = vec![submission("4,6,3", Verdict::Wrong)];
+        log.level("17b".to_string()).wait_until = Some(1733036460);
+
+        let saved = log.to_toml().unwrap();
+        let mut loaded = Log::from_toml(&saved).unwrap();
+
+        for key in ["01a", "17a", "17b"] {
+            let (was, is) = (log.level(key.to_string()), loaded.level(key.to_string()));
+            assert_eq!(was.submissions, is.submissions);
+            assert_eq!(was.wait_until, is.wait_until);
+        }
+    }
+
+    fn page(name: &str) -> (Response, Option<Duration>) {
+        let html = fs::read_to_string(format!(
+            "{}/tests/fixtures/submit/{name}.html",
+            env!("CARGO_MANIFEST_DIR")
+        ))
+        .unwrap();
+
+        parse(&html)
+    }
+
+    #[test]
+    fn pages() {
+        let minute = Some(Duration::from_secs(60));
+
+        assert_eq!(page("correct"), (Response::Judged(Verdict::Correct), None));
+        assert_eq!(
+            page("too-high"),
+            (Response::Judged(Verdict::TooHigh), minute)
+        );
+        assert_eq!(
+            page("too-low"),
+            (
+                Response::Judged(Verdict::TooLow),
+                Some(Duration::from_secs(300))
+            )
+        );
+        assert_eq!(page("wrong"), (Response::Judged(Verdict::Wrong), minute));
+        assert_eq!(
+            page("wait"),
+            (Response::Wait, Some(Duration::from_secs(4 * 60 + 32)))
+        );
+        assert_eq!(page("wrong-level"), (Response::WrongLevel, None));
+        assert_eq!(
+            parse("<html><body>Hmm</body></html>"),
+            (Response::Unknown, None)
+        );
+    }
+
+    #[test]
+    fn cooldowns() {
+        let secs = |text| cooldown(text).map(|d| d.as_secs());
+
+        assert_eq!(secs("You have 4m 32s left to wait."), Some(272));
+        assert_eq!(secs("You have 32s left to wait."), Some(32));
+        assert_eq!(secs("You have 1h 2m 3s left to wait."), Some(3723));
+        assert_eq!(
+            secs("Please wait one minute before trying again."),
+            Some(60)
+        );
+        assert_eq!(
+            secs("please wait 5 minutes before trying again."),
+            Some(300)
+        );
+        assert_eq!(secs("That's the right answer!"), None);
+        assert_eq!(secs("you have to wait after submitting"), None);
+    }
+
+    fn level(submissions: Vec<Submission>) -> Level {
+        Level {
+            wait_until: None,
+            submissions,
+        }
+    }
+
+    #[test]
+    fn bounds() {
+        let level = level(vec![
+            submission(10, Verdict::TooLow),
+            submission(20, Verdict::TooLow),
+            submission(50, Verdict::TooHigh),
+            submission(40, Verdict::TooHigh),
+            submission(30, Verdict::Wrong),
+            submission("abc", Verdict::TooLow),
+        ]);
+
+        assert_eq!(level.bounds(), (Some(20), Some(40)));
+        assert_eq!(Level::default().bounds(), (None, None));
+    }
+
+    #[test]
+    fn reject_bounds() {
+        let level = level(vec![
+            submission(20, Verdict::TooLow),
+            submission(1i128 << 70, Verdict::TooHigh),
+        ]);
+
+        assert_eq!(
+            level.reject(&Answer::Int(15)).unwrap(),
+            "15 is too low, 20 already was"
+        );
+        assert_eq!(
+            level.reject(&Answer::Big(1 << 71)).unwrap(),
+            "2361183241434822606848 is too high, 1180591620717411303424 already was"
+        );
+        assert_eq!(level.reject(&Answer::Int(21)), None);
+        assert_eq!(level.reject(&Answer::Text("21".to_string())), None);
+        // text can't be compared
+        assert_eq!(level.reject(&Answer::Text("abc".to_string())), None);
+    }
+
+    #[test]
+    fn reject_duplicates() {
+        let level = level(vec![
+            submission(42, Verdict::Wrong),
+            submission("4,6,3", Verdict::Wrong),
+        ]);
+
+        for answer in [
+            Answer::Int(42),
+            Answer::Text(" 42".to_string()),
+            Answer::from("4,6,3"),
+        ] {
+            let reason = level.reject(&answer).unwrap();
+            assert!(reason.contains("was already submitted"), "{reason}");
+        }
+
+        assert_eq!(level.reject(&Answer::Int(43)), None);
+    }
+
+    #[test]
+    fn reject_solved() {
+        let level = level(vec![
+            submission(41, Verdict::Wrong),
+            submission(42, Verdict::Correct),
+        ]);
+
+        assert_eq!(
+            level.reject(&Answer::Int(43)).unwrap(),
+            "Already solved with 42"
+        );
+    }
+
+    #[test]
+    fn reject_waiting() {
+        let mut level = level(vec![]);
+
+        level.wait_until = Some(now() + 100);
+        assert!(level
+            .reject(&Answer::Int(1))
+            .unwrap()
+            .starts_with("Wait another"));
+
+        level.wait_until = Some(now() - 1);
+        assert_eq!(level.reject(&Answer::Int(1)), None);
+    }
+}
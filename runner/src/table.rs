@@ -0,0 +1,152 @@
+use std::{io, time::Duration};
+
+use colored::Colorize;
+
+use crate::{
+    answers::Status,
+    colorize,
+    config::config,
+    entry,
+    output::{self, Record},
+    size, Run, MEMORY,
+};
+
+const ANSWER: usize = 24;
+const TIME: usize = 12;
+
+fn truncate(s: &str, width: usize) -> String {
+    if s.chars().count() <= width {
+        return s.to_string();
+    }
+
+    let mut s = s.chars().take(width - 1).collect::<String>();
+    s.push('…');
+    s
+}
+
+// rows are printed as soon as they finish, so a slow day doesn't hide the rest
+#[derive(Default)]
+pub struct Table {
+    parse: Duration,
+    run: Duration,
+    correct: usize,
+    // no recorded answer to compare against
+    unknown: usize,
+    failed: usize,
+}
+
+impl Table {
+    pub fn new() -> Self {
+        if output::machine() {
+            return Self::default();
+        }
+
+        print!(
+            "{:<6} {:<5} {:<ANSWER$} {:<TIME$} {:<TIME$} {:<TIME$} ",
+            "day", "level", "answer", "parse", "run", "total"
+        );
+
+        if MEMORY {
+            print!("{:<TIME$} {:<TIME$} ", "parse peak", "run peak");
+        }
+
+        println!("status");
+
+        Self::default()
+    }
+
+    pub fn row(
+        &mut self,
+        day: usize,
+        level: u8,
+        result: &io::Result<Run>,
+        status: Option<&Status>,
+    ) {
+        let name = entry(day).name;
+
+        if output::machine() {
+            return output::emit(&Record::new(day, level, name, result, status));
+        }
+
+        print!("{:<6} {:<5} ", name.blue(), level.to_string().magenta());
+
+        match result {
+            Ok(run) => {
+                self.parse += run.parse;
+                self.run += run.run;
+
+                match status {
+                    Some(Status::Correct) => self.correct += 1,
+                    Some(Status::Wrong(_)) => self.failed += 1,
+                    Some(Status::Unknown) | None => self.unknown += 1,
+                }
+
+                let thresholds = config().thresholds;
+                let answer = format!("{:<ANSWER$}", truncate(&run.output.to_string(), ANSWER));
+                let answer = match status {
+                    Some(Status::Correct) => answer.green(),
+                    Some(Status::Wrong(_)) => answer.red(),
+                    _ => answer.cyan(),
+                };
+
+                print!(
+                    "{answer} {} {} {} ",
+                    colorize(run.parse, thresholds.parse, TIME),
+                    colorize(run.run, thresholds.run, TIME),
+                    colorize(run.parse + run.run, thresholds.total, TIME),
+                );
+
+                if MEMORY {
+                    print!(
+                        "{:<TIME$} {:<TIME$} ",
+                        size(run.parse_memory.peak),
+                        size(run.run_memory.peak)
+                    );
+                }
+
+                println!("{}", status.unwrap_or(&Status::Unknown));
+            }
+            Err(e) => {
+                self.failed += 1;
+                println!("{}", e.to_string().red());
+            }
+        }
+    }
+
+    pub fn finish(self) {
+        if output::machine() {
+            return;
+        }
+
+        let total = format!("{:?}", self.parse + self.run);
+
+        println!(
+            "{:<6} {:<5} {:<ANSWER$} {:<TIME$} {:<TIME$} {}",
+            "total".bold(),
+            "",
+            "",
+            format!("{:?}", self.parse),
+            format!("{:?}", self.run),
+            total.bold(),
+        );
+
+        let failed = format!("{} failed", self.failed);
+        let failed = if self.failed == 0 {
+            failed.green()
+        } else {
+            failed.red()
+        };
+
+        let unknown = format!("{} unknown", self.unknown);
+        let unknown = if self.unknown == 0 {
+            unknown.normal()
+        } else {
+            unknown.yellow()
+        };
+
+        println!(
+            "{} correct, {unknown}, {failed}",
+            self.correct.to_string().green()
+        );
+    }
+}
@@ -0,0 +1,283 @@
+use std::{
+    env, fmt,
+    fs::{self, OpenOptions},
+    io::{self, IsTerminal, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use colored::Colorize;
+
+use crate::{
+    cli::ClientOpts,
+    client::{Client, Error},
+    config::config,
+};
+
+// wins over any token file
+pub const ENV: &str = "AOC_TOKEN";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    Env,
+    File(PathBuf),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Env => write!(f, "${ENV}"),
+            Self::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+pub fn path() -> PathBuf {
+    config().token_file.clone()
+}
+
+pub fn resolve() -> io::Result<Option<(String, Source)>> {
+    resolve_from(path())
+}
+
+fn resolve_from(path: PathBuf) -> io::Result<Option<(String, Source)>> {
+    if let Ok(token) = env::var(ENV) {
+        if !token.trim().is_empty() {
+            return Ok(Some((token.trim().to_string(), Source::Env)));
+        }
+    }
+
+    match fs::read_to_string(&path) {
+        Ok(token) if !token.trim().is_empty() => {
+            Ok(Some((token.trim().to_string(), Source::File(path))))
+        }
+        Ok(_) => Ok(None),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+// only readable by us, it's as good as a password
+pub fn save(path: &Path, token: &str) -> io::Result<()> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+        options.mode(0o600);
+
+        // mode only applies to new files
+        if path.exists() {
+            fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+        }
+    }
+
+    options.open(path)?.write_all(token.trim().as_bytes())
+}
+
+#[cfg(unix)]
+fn too_open(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    fs::metadata(path).is_ok_and(|meta| meta.permissions().mode() & 0o077 != 0)
+}
+
+#[cfg(not(unix))]
+fn too_open(_: &Path) -> bool {
+    false
+}
+
+// asks for a token if there is none anywhere, only when someone is there to type it
+// the menu redraws its last line instead of adding new ones
+pub fn token(menu: bool) -> io::Result<String> {
+    if let Some((token, _)) = resolve()? {
+        return Ok(token);
+    }
+
+    if !io::stdin().is_terminal() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("No token, set ${ENV} or write {}", path().display()),
+        ));
+    }
+
+    let mut token = String::new();
+
+    if menu {
+        println!("\x1b[1A├─ Enter your token");
+        print!("╰─ ");
+    } else {
+        print!("Enter your token: ");
+    }
+
+    io::stdout().lock().flush()?;
+    io::stdin().read_line(&mut token)?;
+
+    save(&path(), &token)?;
+
+    if menu {
+        println!(
+            "\x1b[2A╰─ {}     \n{}  \n\x1b[2A",
+            "Fetching...".yellow(),
+            " ".repeat(token.len())
+        );
+    }
+
+    Ok(token.trim().to_string())
+}
+
+pub fn check(opts: &ClientOpts) -> ExitCode {
+    let (token, source) = match resolve() {
+        Ok(Some(found)) => found,
+        Ok(None) => {
+            eprintln!(
+                "{}",
+                format!("No token, set ${ENV} or write {}", path().display()).red()
+            );
+            return ExitCode::FAILURE;
+        }
+        Err(e) => {
+            eprintln!("{}", format!("Could not read the token: {e}").red());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("token:\t{source}");
+
+    if let Source::File(path) = &source {
+        if too_open(path) {
+            println!(
+                "\t{}",
+                format!("readable by others, run chmod 600 {}", path.display()).yellow()
+            );
+        }
+    }
+
+    // the first input is always there, and only for a logged in user
+    match Client::new(&token).configure(opts).input(1) {
+        Ok(_) => {
+            println!("status:\t{}", "valid".green());
+            ExitCode::SUCCESS
+        }
+        Err(e @ (Error::BadRequest | Error::Server(_))) => {
+            println!("status:\t{}", "invalid".red());
+            eprintln!("{}", e.to_string().red());
+            ExitCode::FAILURE
+        }
+        Err(e) => {
+            eprintln!("{}", format!("Could not check the token: {e}").red());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+pub fn clear() -> ExitCode {
+    let path = path();
+
+    match fs::remove_file(&path) {
+        Ok(()) => println!("{}", format!("Removed {}", path.display()).green()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            println!("{}", format!("No token at {}", path.display()).yellow())
+        }
+        Err(e) => {
+            eprintln!(
+                "{}",
+                format!("Could not remove {}: {e}", path.display()).red()
+            );
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if env::var(ENV).is_ok_and(|token| !token.trim().is_empty()) {
+        println!("{}", format!("${ENV} is still set").yellow());
+    }
+
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process;
+
+    use super::*;
+
+    fn file(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("advent-token-{}-{name}", process::id()));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[cfg(unix)]
+    fn mode(path: &Path) -> u32 {
+        use std::os::unix::fs::PermissionsExt;
+
+        fs::metadata(path).unwrap().permissions().mode() & 0o777
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn saved_private() {
+        let path = file("new");
+
+        save(&path, "abc\n").unwrap();
+
+        assert_eq!(mode(&path), 0o600);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "abc");
+        assert!(!too_open(&path));
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn resaved_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = file("existing");
+        fs::write(&path, "old token").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+
+        assert!(too_open(&path));
+
+        save(&path, "new").unwrap();
+
+        assert_eq!(mode(&path), 0o600);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    // the only test touching $AOC_TOKEN, so nothing races it
+    #[test]
+    fn env_wins() {
+        let saved = env::var_os(ENV);
+        let path = file("resolve");
+        fs::write(&path, " from file \n").unwrap();
+
+        env::set_var(ENV, " from env ");
+        let from_env = resolve_from(path.clone()).unwrap();
+
+        // blank is as good as unset
+        env::set_var(ENV, " ");
+        let blank = resolve_from(path.clone()).unwrap();
+
+        env::remove_var(ENV);
+        let from_file = resolve_from(path.clone()).unwrap();
+
+        fs::remove_file(&path).unwrap();
+        let missing = resolve_from(path.clone()).unwrap();
+
+        if let Some(token) = saved {
+            env::set_var(ENV, token);
+        }
+
+        let file_source = Some(("from file".to_string(), Source::File(path)));
+
+        assert_eq!(from_env, Some(("from env".to_string(), Source::Env)));
+        assert_eq!(blank, file_source);
+        assert_eq!(from_file, file_source);
+        assert_eq!(missing, None);
+    }
+}
@@ -0,0 +1,223 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, BufRead, BufReader},
+    path::{self, Path, PathBuf},
+    process::{Command, ExitCode, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use colored::Colorize;
+
+use crate::{cli::Target, config::config, dataset, output::Record, MEMORY};
+
+const POLL: Duration = Duration::from_millis(500);
+
+// every file under the path, with when it was last changed
+fn scan(path: &Path, files: &mut BTreeMap<PathBuf, SystemTime>) {
+    let Ok(meta) = fs::metadata(path) else {
+        return;
+    };
+
+    if meta.is_dir() {
+        for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+            scan(&entry.path(), files);
+        }
+    } else if let Ok(modified) = meta.modified() {
+        files.insert(path.to_path_buf(), modified);
+    }
+}
+
+// the day's module (and its submodules), libadvent, and the day's input and examples
+fn snapshot(day: usize) -> BTreeMap<PathBuf, SystemTime> {
+    let name = format!("day{day:02}");
+    let module = config().solutions.join(format!("y{}", config().year));
+    let mut files = BTreeMap::new();
+
+    scan(&module.join(format!("{name}.rs")), &mut files);
+    scan(&module.join(&name), &mut files);
+    // libadvent sits next to the solutions crate
+    scan(&config().solutions.join("../../libadvent/src"), &mut files);
+    scan(&dataset().join("examples.toml"), &mut files);
+
+    for entry in fs::read_dir(dataset()).into_iter().flatten().flatten() {
+        let file = entry.file_name().to_string_lossy().into_owned();
+
+        if file == format!("{name}.txt") || file.starts_with(&format!("{name}.example")) {
+            scan(&entry.path(), &mut files);
+        }
+    }
+
+    files
+}
+
+fn cargo(command: &str) -> Command {
+    let mut cargo = Command::new("cargo");
+    cargo
+        .current_dir(&config().solutions)
+        .args([command, "-q", "-p", "advent24-runner"]);
+
+    // the same build as the one that's watching
+    if !cfg!(debug_assertions) {
+        cargo.arg("--release");
+    }
+
+    if MEMORY {
+        cargo.args(["--features", "memory"]);
+    }
+
+    cargo
+}
+
+// the rebuilt runner's records, or none if it didn't build
+fn run(target: Target, example: &Option<Option<String>>) -> io::Result<Option<Vec<Record>>> {
+    if !cargo("build").status()?.success() {
+        return Ok(None);
+    }
+
+    let mut command = cargo("run");
+    command
+        .arg("--")
+        .arg("--dataset")
+        .arg(path::absolute(&config().dataset)?)
+        .args(["--year", &config().year.to_string()])
+        .args(["--format", "json"]);
+
+    if let Some(timeout) = config().timeout {
+        command.args(["--timeout", &timeout.as_secs_f64().to_string()]);
+    }
+
+    command.args(["run", &target.to_string()]);
+
+    match example {
+        Some(Some(k)) => command.args(["--example", k]),
+        Some(None) => command.arg("--example"),
+        None => &mut command,
+    };
+
+    // errors that aren't records (a missing input, say) go straight through on stderr
+    let mut child = command.stdout(Stdio::piped()).spawn()?;
+    let mut records = vec![];
+
+    if let Some(stdout) = child.stdout.take() {
+        for line in BufReader::new(stdout).lines() {
+            if let Ok(record) = serde_json::from_str(&line?) {
+                records.push(record);
+            }
+        }
+    }
+
+    child.wait()?;
+    Ok(Some(records))
+}
+
+fn change(now: u64, was: u64) -> String {
+    let change = (now as f64 - was as f64) / was as f64 * 100.0;
+    let disp = format!("{change:+.1}%");
+
+    if change > 10.0 {
+        format!("{}", disp.red())
+    } else if change < -10.0 {
+        format!("{}", disp.green())
+    } else {
+        disp
+    }
+}
+
+// prints the records, and what changed since the previous run
+fn show(records: &[Record], previous: &BTreeMap<(String, u8), Record>) {
+    for record in records {
+        let was = previous.get(&(record.name.clone(), record.level));
+        print!(
+            "{} - {}\t",
+            record.name.blue(),
+            format!("Level {}", record.level).magenta()
+        );
+
+        if let Some(error) = &record.error {
+            println!("{}", error.red());
+            continue;
+        }
+
+        let answer = record
+            .answer
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_default();
+        let answer = match record.status.as_deref() {
+            Some("correct") => answer.green(),
+            Some("wrong") => answer.red(),
+            _ => answer.cyan(),
+        };
+
+        match was.and_then(|was| was.answer.as_ref()) {
+            Some(before) if Some(before) != record.answer.as_ref() => {
+                print!("{answer} {}", format!("(was {before})").yellow())
+            }
+            _ => print!("{answer}"),
+        }
+
+        let total = record.total_ns.unwrap_or_default();
+        print!("\t{:?}", Duration::from_nanos(total));
+
+        match was.and_then(|was| was.total_ns) {
+            Some(before) if before > 0 => println!(" {}", change(total, before)),
+            _ => println!(),
+        }
+    }
+}
+
+pub fn watch(target: Target, example: Option<Option<String>>) -> ExitCode {
+    let Target::Day(day, _) = target else {
+        eprintln!("{}", "watch needs a single day, like 16b".red());
+        return ExitCode::FAILURE;
+    };
+
+    let mut previous = BTreeMap::new();
+    let mut files = BTreeMap::new();
+
+    loop {
+        let now = snapshot(day);
+
+        if now == files {
+            thread::sleep(POLL);
+            continue;
+        }
+
+        // a new or deleted file counts as a change too
+        let changed = now
+            .iter()
+            .filter(|(path, time)| files.get(*path) != Some(*time))
+            .map(|(path, _)| path)
+            .chain(files.keys().filter(|path| !now.contains_key(*path)))
+            .filter_map(|path| path.file_name())
+            .map(|file| file.to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        let first = files.is_empty();
+        files = now;
+
+        print!("\x1b[2J\x1b[H");
+
+        if first {
+            println!("{}", format!("Watching {target}").yellow());
+        } else {
+            println!("{}", format!("Changed {}", changed.join(", ")).yellow());
+        }
+
+        match run(target, &example) {
+            Ok(Some(records)) => {
+                show(&records, &previous);
+                previous = records
+                    .into_iter()
+                    .map(|record| ((record.name.clone(), record.level), record))
+                    .collect();
+            }
+            Ok(None) => println!("{}", "Build failed, waiting for changes".red()),
+            Err(e) => {
+                eprintln!("{}", format!("Could not run cargo: {e}").red());
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+}
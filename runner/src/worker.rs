@@ -0,0 +1,213 @@
+use std::{
+    env,
+    io::{self, Read, Write},
+    panic, path,
+    process::{Child, Command, ExitCode, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use libadvent::{memory::Memory, solution::Overrides, Answer};
+use serde::{Deserialize, Serialize};
+
+use crate::{config::config, entry, Run};
+
+// what a worker prints on success
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Reply {
+    output: Answer,
+    parse_ns: u64,
+    run_ns: u64,
+    parse_memory: Memory,
+    run_memory: Memory,
+}
+
+// the hidden `worker` command: solves the level for the input on stdin
+pub fn serve(day: usize, level: u8, overrides: &Overrides) -> ExitCode {
+    // only the message and where it happened, the parent turns it into an error row
+    panic::set_hook(Box::new(|info| eprintln!("{info}")));
+
+    let mut input = String::new();
+
+    if let Err(e) = io::stdin().read_to_string(&mut input) {
+        eprintln!("could not read the input: {e}");
+        return ExitCode::FAILURE;
+    }
+
+    match entry(day).level(level)(&input, overrides) {
+        Ok(run) => {
+            let reply = Reply {
+                output: run.output,
+                parse_ns: run.parse.as_nanos() as u64,
+                run_ns: run.run.as_nanos() as u64,
+                parse_memory: run.parse_memory,
+                run_memory: run.run_memory,
+            };
+
+            println!("{}", reply.to_line());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+impl Reply {
+    fn to_line(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    // anything the level printed comes before the reply
+    fn from_stdout(stdout: &str) -> io::Result<Self> {
+        serde_json::from_str(stdout.lines().last().unwrap_or_default())
+            .map_err(|e| io::Error::other(format!("bad reply from the worker: {e}")))
+    }
+}
+
+fn spawn(day: usize, level: u8, overrides: &Overrides) -> io::Result<Child> {
+    let mut command = Command::new(env::current_exe()?);
+
+    // the same file the parent read, not whatever the worker would find on its own
+    if let Some(file) = &config().file {
+        command.arg("--config").arg(path::absolute(file)?);
+    }
+
+    command.args(["--year", &config().year.to_string()]).args([
+        "worker",
+        &day.to_string(),
+        &level.to_string(),
+    ]);
+
+    for (key, value) in overrides {
+        command.arg(format!("--param={key}={value}"));
+    }
+
+    command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+}
+
+// drained on the side, so a level that prints a lot can't block on a full pipe
+fn read(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut s = String::new();
+
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_string(&mut s);
+        }
+
+        s
+    })
+}
+
+// runs the level in a child process, so a panic or a level that never finishes only fails that level
+pub fn solve(day: usize, level: u8, input: &str, overrides: &Overrides) -> io::Result<Run> {
+    let mut child = spawn(day, level, overrides)?;
+    let stdout = read(child.stdout.take());
+    let stderr = read(child.stderr.take());
+
+    // a worker that died before reading all of it still says why on stderr
+    if let Some(mut stdin) = child.stdin.take() {
+        match stdin.write_all(input.as_bytes()) {
+            Err(e) if e.kind() != io::ErrorKind::BrokenPipe => return Err(e),
+            _ => {}
+        }
+    }
+
+    let timeout = config().timeout;
+    let start = Instant::now();
+
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+
+        match timeout {
+            Some(timeout) if start.elapsed() >= timeout => {
+                child.kill()?;
+                child.wait()?;
+
+                return Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    format!("timed out after {timeout:?}"),
+                ));
+            }
+            _ => {}
+        }
+
+        thread::sleep(Duration::from_millis(5));
+    };
+
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+
+    if !status.success() {
+        let message = stderr.lines().collect::<Vec<_>>().join(" ");
+        let message = if message.is_empty() {
+            format!("worker exited with {status}")
+        } else {
+            message
+        };
+
+        return Err(io::Error::other(message));
+    }
+
+    let reply = Reply::from_stdout(&stdout)?;
+
+    Ok(Run {
+        output: reply.output,
+        parse: Duration::from_nanos(reply.parse_ns),
+        run: Duration::from_nanos(reply.run_ns),
+        parse_memory: reply.parse_memory,
+        run_memory: reply.run_memory,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reply(output: Answer) -> Reply {
+        Reply {
+            output,
+            parse_ns: 12,
+            run_ns: 345,
+            parse_memory: Memory::default(),
+            run_memory: Memory {
+                allocs: 1,
+                bytes: 2,
+                peak: 3,
+            },
+        }
+    }
+
+    #[test]
+    fn replies_round_trip() {
+        for output in [
+            Answer::Int(-7),
+            Answer::Big(i64::MAX as i128 * 4),
+            Answer::Text("LGPKXZQ".to_string()),
+        ] {
+            let reply = reply(output);
+            assert_eq!(Reply::from_stdout(&reply.to_line()).unwrap(), reply);
+        }
+    }
+
+    #[test]
+    fn level_output_before_the_reply() {
+        let reply = reply(Answer::Big(1 << 100));
+        let stdout = format!("debugging\n{{\"not\": \"a reply\"}}\n{}\n", reply.to_line());
+
+        assert_eq!(Reply::from_stdout(&stdout).unwrap(), reply);
+    }
+
+    #[test]
+    fn bad_reply() {
+        let e = Reply::from_stdout("").unwrap_err();
+        assert!(e.to_string().starts_with("bad reply from the worker"));
+    }
+}
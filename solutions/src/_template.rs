@@ -3,12 +3,13 @@ use libadvent::IsInput;
 pub struct Input;
 
 impl IsInput for Input {
-    fn parse(s: &str) -> Self {
+    fn parse(_s: &str) -> Self {
         todo!()
     }
 }
 
 problem_parser!(ty Input);
+solution!(Input);
 
 pub fn level1(_: Input) -> i32 {
     0
@@ -14,28 +14,39 @@
 extern crate libadvent;
 extern crate lazy_static;
 
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
+use libadvent::solution::{number, Entry};
+
+// registering a day is adding it to its year's list
+macro_rules! solutions {
+    ($($day:ident),* $(,)?) => {
+        $(pub mod $day;)*
+
+        // ordered by day
+        pub static SOLUTIONS: &[::libadvent::solution::Entry] =
+            &[$(::libadvent::solution::Entry::new::<$day::Day>(stringify!($day))),*];
+    };
+}
+
+// one module per year, each with its own solutions!(...)
+macro_rules! years {
+    ($($year:ident),* $(,)?) => {
+        $(pub mod $year;)*
+
+        // ordered by year
+        pub static YEARS: &[(u32, &[Entry])] =
+            &[$((number(stringify!($year)) as u32, $year::SOLUTIONS)),*];
+    };
+}
+
+years!(y2024);
+
+pub fn days(year: u32) -> Option<&'static [Entry]> {
+    YEARS
+        .iter()
+        .find(|(y, _)| *y == year)
+        .map(|(_, days)| *days)
+}
+
+pub fn get(year: u32, day: usize) -> Option<&'static Entry> {
+    days(year)?.iter().find(|entry| entry.day == day)
+}
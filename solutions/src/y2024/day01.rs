@@ -4,6 +4,7 @@ use itertools::Itertools;
 use libadvent::Seperated;
 
 problem_parser!(Seperated::newline(Seperated::whitespace(ty_parser!(i32))) => Vec<Vec<i32>>);
+solution!(Vec<Vec<i32>>);
 
 pub fn level1(input: Vec<Vec<i32>>) -> i32 {
     let mut side1 = input.iter().map(|v| v[0]).collect_vec();
@@ -2,6 +2,7 @@ use itertools::Itertools;
 use libadvent::Seperated;
 
 problem_parser!(Seperated::newline(Seperated::whitespace(ty_parser!(i32))) => Vec<Vec<i32>>);
+solution!(Vec<Vec<i32>>);
 
 fn abs_ok(abs: i32) -> bool {
     (1..=3).contains(&abs)
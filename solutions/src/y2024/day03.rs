@@ -1,4 +1,5 @@
 problem_parser!(ty_parser!(String) => String);
+solution!(String);
 
 pub fn level1(input: String) -> i32 {
     let mut input = input.as_str();
@@ -2,6 +2,7 @@ use itertools::Itertools;
 use libadvent::{Seperated, Take};
 
 problem_parser!(Seperated::newline(Take::one(ty_parser!(char))) => Vec<Vec<char>>);
+solution!(Vec<Vec<char>>);
 
 fn findxmas(input: &[Vec<char>], (mut i, mut j): (usize, usize), (offi, offj): (i32, i32)) -> bool {
     let len = input.len();
@@ -1,8 +1,6 @@
-use std::{
-    cmp::Ordering::{self, Equal, Greater, Less},
-    collections::HashSet,
-    str::FromStr,
-};
+use std::str::FromStr;
+
+use libadvent::graph::Graph;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct OrderingRule {
@@ -22,12 +20,6 @@ impl FromStr for OrderingRule {
     }
 }
 
-impl From<OrderingRule> for (u32, u32) {
-    fn from(other: OrderingRule) -> (u32, u32) {
-        (other.lhs, other.rhs)
-    }
-}
-
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Update {
     nums: Vec<u32>,
@@ -45,47 +37,19 @@ impl FromStr for Update {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Input {
-    rules: HashSet<(u32, u32)>,
+    rules: Graph<u32>,
     updates: Vec<Update>,
 }
 
 impl Input {
-    fn rules_containing(&self, n: u32) -> Vec<(u32, Ordering)> {
-        self.rules
-            .iter()
-            .filter(|(a, b)| *a == n || *b == n)
-            .map(|(a, b)| if *a == n { (*b, Greater) } else { (*a, Less) })
-            .collect()
-    }
-
-    fn rules_both(&self, n: u32, m: u32) -> Option<Ordering> {
-        self.rules
-            .iter()
-            .find(|ab| **ab == (n, m) || **ab == (m, n))
-            .map(|(a, _)| if *a == n { Less } else { Greater })
-    }
-
     fn follows(&self, i: usize) -> bool {
         let up = &self.updates[i];
 
         for j in 0..up.nums.len() {
-            let r = self.rules_containing(up.nums[j]);
-
-            for (other, side) in r {
-                match side {
-                    Less => {
-                        if up.nums[j + 1..].contains(&other) {
-                            return false;
-                        }
-                    }
-                    Greater => {
-                        if up.nums[..j].contains(&other) {
-                            return false;
-                        }
-                    }
-                    Equal => {
-                        continue;
-                    }
+            for k in j + 1..up.nums.len() {
+                // a rule says the later page must come first
+                if self.rules.contains_edge(up.nums[k], up.nums[j]) {
+                    return false;
                 }
             }
         }
@@ -94,16 +58,13 @@ impl Input {
     }
 
     fn reorder(&mut self, i: usize) {
-        let this = self.clone();
-
-        self.updates[i].nums.sort_by(|a, b| {
-            let ord = this.rules_both(*a, *b);
+        let nums = &mut self.updates[i].nums;
 
-            match ord {
-                Some(ord) => ord,
-                None => a.cmp(b),
-            }
-        });
+        // the full ruleset has cycles, but the pages of a single update never do
+        *nums = match self.rules.subgraph(nums.iter().copied()).toposort() {
+            Ok(order) => order,
+            Err(cycle) => panic!("Update {i} cannot be ordered, {cycle}"),
+        };
     }
 
     fn middle(&self, i: usize) -> u32 {
@@ -117,25 +78,31 @@ impl FromStr for Input {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut parts = s.split("\n\n");
 
-        let rules = parts
-            .next()
-            .unwrap()
-            .lines()
-            .map(|l| l.parse::<OrderingRule>().unwrap().into())
-            .collect();
+        let mut rules = Graph::directed();
+
+        for l in parts.next().unwrap().lines() {
+            let OrderingRule { lhs, rhs } = l.parse().unwrap();
+            rules.add_edge(lhs, rhs);
+        }
 
         let updates = parts
             .next()
             .unwrap()
             .lines()
             .map(|l| l.parse().unwrap())
-            .collect();
+            .collect::<Vec<Update>>();
+
+        // pages without any rules still need to survive a reorder
+        for up in &updates {
+            up.nums.iter().for_each(|n| rules.add_node(*n));
+        }
 
         Ok(Self { rules, updates })
     }
 }
 
 problem_parser!(ty_parser!(Input));
+solution!(Input);
 
 pub fn level1(input: Input) -> u32 {
     let mut sum = 0;
@@ -193,6 +193,7 @@ impl fmt::Display for Input {
 }
 
 problem_parser!(ty_parser!(Input));
+solution!(Input);
 
 // instead of storing two bytes per square, we can store two bits per square
 #[derive(Clone, Debug, PartialEq)]
@@ -1,4 +1,4 @@
-use libadvent::TyParser;
+use libadvent::math::Digits;
 use rayon::iter::{ParallelBridge, ParallelIterator};
 
 use std::{num::ParseIntError, str::FromStr};
@@ -126,10 +126,7 @@ impl<const N: u8> Problem<N> {
             match op {
                 Operation::Add => total += num,
                 Operation::Mul => total *= num,
-                Operation::Concat => {
-                    let dplaces = (*num as f64).log10() as u32 + 1;
-                    total = total * 10u64.pow(dplaces) + num;
-                }
+                Operation::Concat => total = total.concat_digits(*num),
             }
         }
 
@@ -154,7 +151,20 @@ impl<const N: u8> Problem<N> {
     }
 }
 
-impl<const N: u8> FromStr for Problem<N> {
+// the operators are only decided per level, so the input is just the equations
+#[derive(Clone, Debug, PartialEq)]
+pub struct Equation {
+    numbers: Vec<u64>,
+    total: u64,
+}
+
+impl<const N: u8> From<&Equation> for Problem<N> {
+    fn from(equation: &Equation) -> Self {
+        Self::new(equation.numbers.clone(), equation.total)
+    }
+}
+
+impl FromStr for Equation {
     type Err = ParseIntError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -168,40 +178,40 @@ impl<const N: u8> FromStr for Problem<N> {
             .map(Result::unwrap)
             .collect();
 
-        Ok(Self::new(numbers, total))
+        Ok(Self { numbers, total })
     }
 }
 
-pub struct Input<const N: u8> {
-    problems: Vec<Problem<N>>,
+pub struct Input {
+    equations: Vec<Equation>,
 }
 
-impl<const N: u8> FromStr for Input<N> {
+impl FromStr for Input {
     type Err = ParseIntError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let problems = s.lines().map(str::parse).map(Result::unwrap).collect();
+        let equations = s.lines().map(str::parse).map(Result::unwrap).collect();
 
-        Ok(Self { problems })
+        Ok(Self { equations })
     }
 }
 
-pub fn parser<const N: u8>() -> TyParser<Input<N>> {
-    TyParser::default()
-}
+problem_parser!(ty_parser!(Input));
+solution!(Input);
 
-pub fn level1(mut data: Input<2>) -> usize {
-    data.problems
-        .iter_mut()
+// sum of the equations that can be made true with N operators
+fn calibrate<const N: u8>(data: &Input) -> usize {
+    data.equations
+        .iter()
         .par_bridge()
-        .map(Problem::solve)
+        .map(|equation| Problem::<N>::from(equation).solve())
         .sum::<u64>() as usize
 }
 
-pub fn level2(mut data: Input<3>) -> usize {
-    data.problems
-        .iter_mut()
-        .par_bridge()
-        .map(Problem::solve)
-        .sum::<u64>() as usize
+pub fn level1(data: Input) -> usize {
+    calibrate::<2>(&data)
+}
+
+pub fn level2(data: Input) -> usize {
+    calibrate::<3>(&data)
 }
@@ -236,6 +236,7 @@ impl FromStr for Input {
 }
 
 problem_parser!(ty_parser!(Input));
+solution!(Input);
 
 pub fn level1(mut input: Input) -> usize {
     input.antinodify_all();
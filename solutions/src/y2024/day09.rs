@@ -65,6 +65,7 @@ impl fmt::Display for Input {
 }
 
 problem_parser!(ty_parser!(Input));
+solution!(Input);
 
 pub fn level1(input: Input) -> usize {
     let mut input = input.process();
@@ -4,6 +4,7 @@ use libadvent::{Seperated, Take};
 use rayon::iter::{IntoParallelIterator, ParallelBridge, ParallelIterator};
 
 problem_parser!(Seperated::newline(Take::one(ty_parser!(u8))) => Vec<Vec<u8>>);
+solution!(Vec<Vec<u8>>);
 
 type ArcInput = Arc<[Arc<[u8]>]>;
 
@@ -1,10 +1,11 @@
 use std::collections::HashMap;
 
 use itertools::Itertools;
-use libadvent::Seperated;
+use libadvent::{math::Digits, Seperated};
 
 problem_parser!(Seperated::whitespace(ty_parser!(u64)));
-type Input = Vec<u64>;
+pub type Input = Vec<u64>;
+solution!(Input);
 
 fn solve(data: Input, steps: usize) -> usize {
     // store each number and the number of times it appears
@@ -28,7 +29,7 @@ fn solve(data: Input, steps: usize) -> usize {
             if num == 0 {
                 add(num + 1);
             } else {
-                let dplaces = ((num as f64).log10() as u32) + 1;
+                let dplaces = num.num_digits();
                 if dplaces % 2 == 0 {
                     let lhs = num / 10u64.pow(dplaces / 2);
                     let rhs = num % 10u64.pow(dplaces / 2);
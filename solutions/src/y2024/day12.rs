@@ -247,6 +247,7 @@ impl FloodFill {
 }
 
 problem_parser!(ty_parser!(Input));
+solution!(Input);
 
 pub fn level1(input: Input) -> usize {
     let mut price = 0;
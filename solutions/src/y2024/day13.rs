@@ -2,6 +2,7 @@
 use itertools::Itertools;
 use libadvent::{
     grid::{Offset, Point},
+    math::{solve_linear, LinearSolution},
     IsInput, Seperated,
 };
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
@@ -21,52 +22,29 @@ impl ClawMachine {
         }
     }
 
-    #[allow(non_snake_case)] // linear algebra
     fn solve(self) -> Option<usize> {
-        // Pa * a + Pb * b = r
-        //
         // Pa * Ax + Pb * Bx = Rx
         // Pa * Ay + Pb * By = Ry
-        //
-        // Thanks, Cramer
-        // and 3Blue1Brown for helping me understand wtfever this is
+        let Offset(ay, ax) = self.a;
+        let Offset(by, bx) = self.b;
+        let Point(ry, rx) = self.r;
 
-        let Offset(Ay, Ax) = self.a;
-        let Offset(By, Bx) = self.b;
-        let Point(Ry, Rx) = self.r;
-
-        // Pa = Dx / D
-        // Pb can be solved later
-
-        // D = det (| Ay By |) = Ay * Bx - By * Ax
-        //         (| Ax Bx |)
-        let D = Ay * Bx - By * Ax;
-
-        // Dx = det (| Bx By |) = Bx * Ry - By * Rx
-        //          (| Rx Ry |)
-        let Dx = Bx * Ry - By * Rx;
-
-        // check for errors (i.e. Dx and Dy are not multiples of D)
-        if Dx % D != 0 {
+        let LinearSolution::Unique(presses) =
+            solve_linear(&[vec![ax, bx], vec![ay, by]], &[rx, ry])
+        else {
             return None;
-        }
-
-        // Pa = Dx / D
-        let Pa = Dx / D;
+        };
 
-        // check for errors v2
-        if (Rx - Pa * Ax) % Bx != 0 {
-            return None;
-        }
+        // can't press a button half a time, or negative times
+        let [pa, pb] = presses[..] else {
+            unreachable!()
+        };
 
-        // therefore, Pb = (Rx - Pa * Ax) / Bx
-        let Pb = (Rx - Pa * Ax) / Bx;
+        let pa = usize::try_from(pa.to_integer()?).ok()?;
+        let pb = usize::try_from(pb.to_integer()?).ok()?;
 
         // b costs 1 point, a costs 3
-        let apts = Pa * 3;
-        let bpts = Pb;
-
-        Some((apts + bpts) as usize)
+        Some(pa * 3 + pb)
     }
 }
 
@@ -106,7 +84,8 @@ impl IsInput for ClawMachine {
 }
 
 problem_parser!(Seperated::new("\n\n", ty_parser!(ClawMachine)));
-type Input = Vec<ClawMachine>;
+pub type Input = Vec<ClawMachine>;
+solution!(Input);
 
 pub fn level1(data: Input) -> usize {
     data.into_par_iter()
@@ -1,12 +1,22 @@
 use itertools::Itertools;
 use libadvent::{
     grid::{Offset, Point},
-    IsInput, Seperated,
+    math, IsInput, Seperated,
 };
 
 pub const MAX_W: isize = 101;
 pub const MAX_H: isize = 103;
 
+params! {
+    pub struct Params {
+        #[range(1..)]
+        pub width: isize = MAX_W,
+        #[range(1..)]
+        pub height: isize = MAX_H,
+        pub seconds: usize = 100,
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Robot {
     position: Point,
@@ -14,20 +24,20 @@ pub struct Robot {
 }
 
 impl Robot {
-    pub fn step(&mut self, n: usize) {
+    pub fn step(&mut self, n: usize, params: &Params) {
         self.position += self.velocity * n;
 
         // wrap neg
-        self.position.rem((MAX_H, MAX_W));
+        self.position.rem((params.height, params.width));
     }
 
-    pub fn steponce(&mut self) {
-        self.step(1);
+    pub fn steponce(&mut self, params: &Params) {
+        self.step(1, params);
     }
 
-    pub fn quadrant(&self) -> Option<usize> {
-        let halfw = MAX_W / 2;
-        let halfh = MAX_H / 2;
+    pub fn quadrant(&self, params: &Params) -> Option<usize> {
+        let halfw = params.width / 2;
+        let halfh = params.height / 2;
 
         if self.position.x() < halfw {
             if self.position.y() < halfh {
@@ -65,13 +75,14 @@ impl IsInput for Robot {
 
 // pub type Parser = NewlineSeperated<Robot>;
 problem_parser!(Seperated::newline(ty_parser!(Robot)));
-type Input = Vec<Robot>;
+pub type Input = Vec<Robot>;
+solution!(Input, Params);
 
-pub fn level1(data: Input) -> usize {
+pub fn level1(data: Input, params: &Params) -> usize {
     data.into_iter()
         .filter_map(|mut robot| {
-            robot.step(100);
-            robot.quadrant()
+            robot.step(params.seconds, params);
+            robot.quadrant(params)
         })
         .fold([0; 4], |mut acc, it| {
             acc[it - 1] += 1;
@@ -81,20 +92,25 @@ pub fn level1(data: Input) -> usize {
         .product()
 }
 
-pub fn level2(mut data: Input) -> usize {
-    let step = |data: &mut Input| data.iter_mut().for_each(Robot::steponce);
+pub fn level2(mut data: Input, params: &Params) -> usize {
+    let step = |data: &mut Input| data.iter_mut().for_each(|r| r.steponce(params));
     let threshold = 60; // guess'd
 
-    for i in 1.. {
+    // every robot is back where it started after lcm(w, h) steps, no point looking further
+    let period = math::lcm(params.width as u64, params.height as u64) as usize;
+    let cols = params.width / 11 + 1;
+    let rows = params.height / 11 + 1;
+
+    for i in 1..=period {
         step(&mut data);
 
-        let mut draw = [0u32; 100];
+        let mut draw = vec![0u32; (rows * cols) as usize];
 
         for robot in &data {
             // basically blur
             let re_y = robot.position.y() / 11;
             let re_x = robot.position.x() / 11;
-            let idx = (re_y * 10 + re_x) as usize;
+            let idx = (re_y * cols + re_x) as usize;
 
             draw[idx] += 1;
 
@@ -104,5 +120,5 @@ pub fn level2(mut data: Input) -> usize {
         }
     }
 
-    0
+    panic!("no tree in {period} steps");
 }
@@ -30,6 +30,7 @@ impl Parser for InputParser {
 }
 
 problem_parser!(parser InputParser);
+solution!(<InputParser as Parser>::Output);
 
 pub use level1_m::level1;
 pub use level2_m::level2;
@@ -135,6 +135,7 @@ impl IsInput for Maze {
 }
 
 problem_parser!(ty Maze);
+solution!(Maze);
 
 pub fn level1(maze: Maze) -> usize {
     maze.dijkstra()
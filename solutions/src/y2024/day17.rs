@@ -197,6 +197,7 @@ impl IsInput for VirtualMachine {
 }
 
 problem_parser!(ty VirtualMachine);
+solution!(VirtualMachine);
 
 pub fn level1(mut vm: VirtualMachine) -> String {
     vm.run();
@@ -0,0 +1,155 @@
+use std::{cmp, collections::BinaryHeap};
+
+use libadvent::{
+    disjoint::GridSets,
+    grid::{Direction, Grid, Point, PointParser},
+    Reverse, Seperated,
+};
+
+problem_parser!(Seperated::newline(PointParser::new(Reverse::new(
+    Seperated::comma(ty_parser!(usize))
+))));
+solution!(Input, Params; check = check);
+
+pub type Input = Vec<Point>;
+
+params! {
+    pub struct Params {
+        #[range(1..)]
+        pub size: usize = 71,
+        pub bytes: usize = 1024,
+    }
+}
+
+impl Params {
+    fn corners(&self) -> (Point, Point) {
+        (Point::new(0, 0), Point::new(self.size - 1, self.size - 1))
+    }
+}
+
+// every byte has to land on the grid, and level 1 needs `bytes` of them
+fn check(points: &Input, params: &Params) -> Result<(), String> {
+    if params.bytes > points.len() {
+        return Err(format!(
+            "bytes: {} is more than the {} in the input",
+            params.bytes,
+            points.len()
+        ));
+    }
+
+    let size = params.size as isize;
+
+    match points.iter().find(|p| p.x() >= size || p.y() >= size) {
+        Some(p) => Err(format!(
+            "size: {size} is too small for the byte at {},{}",
+            p.x(),
+            p.y()
+        )),
+        None => Ok(()),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct State {
+    cost: usize,
+    coord: Point,
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        other
+            .cost
+            .cmp(&self.cost)
+            .then_with(|| self.coord.cmp(&other.coord))
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn dijkstra(grid: &Grid<bool>, (src, dest): (Point, Point)) -> usize {
+    let mut dist = grid.map(|_, _| usize::MAX);
+    let mut heap = BinaryHeap::new();
+
+    dist[src] = 0;
+    heap.push(State {
+        cost: 0,
+        coord: src,
+    });
+
+    while let Some(State { cost, coord }) = heap.pop() {
+        if coord == dest {
+            return cost;
+        }
+
+        if cost > dist[coord] {
+            continue;
+        }
+
+        for dir in Direction::ALL {
+            let next = coord + *dir;
+            let cost = cost + 1;
+
+            if (!grid.inbounds(next)) || grid[next] {
+                continue;
+            }
+
+            if cost < dist[next] {
+                dist[next] = cost;
+                heap.push(State { cost, coord: next });
+            }
+        }
+    }
+
+    usize::MAX
+}
+
+pub fn level1(points: Input, params: &Params) -> usize {
+    let mut grid = Grid::new(false, params.size, params.size);
+
+    for point in &points[0..params.bytes] {
+        grid[*point] = true;
+    }
+
+    dijkstra(&grid, params.corners())
+}
+
+pub fn level2(points: Input, params: &Params) -> String {
+    let (src, dest) = params.corners();
+
+    let mut grid = Grid::new(false, params.size, params.size);
+
+    for point in &points {
+        grid[*point] = true;
+    }
+
+    let mut sets = GridSets::for_grid(&grid);
+    let join = |sets: &mut GridSets, grid: &Grid<bool>, p: Point| {
+        for dir in Direction::ALL {
+            let next = p + *dir;
+
+            if grid.is(next, &false) {
+                sets.union(p, next);
+            }
+        }
+    };
+
+    for (p, _) in grid.iter().filter(|(_, byte)| !**byte) {
+        join(&mut sets, &grid, p);
+    }
+
+    // un-drop the bytes in reverse, the one that reconnects the corners is the one that split them
+    for point in points.iter().rev() {
+        grid[*point] = false;
+        join(&mut sets, &grid, *point);
+
+        if sets.connected(src, dest) {
+            return format!("{},{}", point.1, point.0);
+        }
+    }
+
+    panic!("Corners are never disconnected");
+}
@@ -1,7 +1,5 @@
-use std::collections::HashMap;
-
 use itertools::Itertools;
-use libadvent::{IsInput, Parser, Seperated};
+use libadvent::{memo::Memo, IsInput, Parser, Seperated};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
 pub struct Input {
@@ -35,11 +33,11 @@ impl Input {
     }
 
     pub fn possible_ways(&self, towel: &str) -> usize {
-        self.possible_ways_memoized(towel, &mut HashMap::new())
+        self.possible_ways_memoized(towel, &mut Memo::new())
     }
 
-    fn possible_ways_memoized(&self, towel: &str, memo: &mut HashMap<String, usize>) -> usize {
-        if let Some(&count) = memo.get(towel) {
+    fn possible_ways_memoized(&self, towel: &str, memo: &mut Memo<String, usize>) -> usize {
+        if let Some(count) = memo.get(towel) {
             return count;
         }
 
@@ -60,6 +58,7 @@ impl Input {
 }
 
 problem_parser!(ty Input);
+solution!(Input);
 
 pub fn level1(input: Input) -> usize {
     input
@@ -6,6 +6,13 @@ use libadvent::{
     IsInput, Parser,
 };
 
+params! {
+    pub struct Params {
+        // a cheat only counts if it saves at least this many steps
+        pub save: usize = 100,
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Square {
     Wall,
@@ -51,7 +58,7 @@ pub struct Input {
 }
 
 impl Input {
-    pub fn dijkstra(&self, hack_steps: usize) -> usize {
+    pub fn dijkstra(&self, hack_steps: usize, save: usize) -> usize {
         let mut dist = self.grid.map(|_, _| usize::MAX);
         let mut heap = VecDeque::from([State {
             point: self.src,
@@ -105,8 +112,8 @@ impl Input {
                 continue;
             }
 
-            // we need to save at least 100 steps for it to count towards the answer
-            if diff >= 100 {
+            // we need to save at least `save` steps for it to count towards the answer
+            if diff >= save {
                 better += 1;
             }
         }
@@ -131,11 +138,12 @@ impl IsInput for Input {
 }
 
 problem_parser!(ty Input);
+solution!(Input, Params);
 
-pub fn level1(input: Input) -> usize {
-    input.dijkstra(2)
+pub fn level1(input: Input, params: &Params) -> usize {
+    input.dijkstra(2, params.save)
 }
 
-pub fn level2(input: Input) -> usize {
-    input.dijkstra(20)
+pub fn level2(input: Input, params: &Params) -> usize {
+    input.dijkstra(20, params.save)
 }
@@ -4,7 +4,8 @@ use libadvent::Seperated;
 use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
 
 problem_parser!(Seperated::newline(ty_parser!(isize)));
-type Input = Vec<isize>;
+pub type Input = Vec<isize>;
+solution!(Input);
 
 pub fn next_secret(mut n: isize) -> isize {
     // mix n by n*64
@@ -0,0 +1,52 @@
+use itertools::Itertools;
+use libadvent::{dot::Dot, graph::Graph, FuncParser, Parser, Seperated};
+
+pub struct InputParser;
+
+impl Parser for InputParser {
+    type Output = Graph<[char; 2]>;
+
+    fn parse(&mut self, s: &str) -> Self::Output {
+        let mut graph = Graph::undirected();
+
+        Seperated::newline(FuncParser::new(|line| {
+            let chars = line.chars().collect_vec();
+            let node1 = &chars[0..2];
+            let node2 = &chars[3..];
+
+            let node1 = <[char; 2]>::try_from(node1).unwrap();
+            let node2 = <[char; 2]>::try_from(node2).unwrap();
+
+            graph.add_edge(node1, node2);
+        }))
+        .parse(s);
+
+        graph
+    }
+}
+
+problem_parser!(InputParser => Graph<[char; 2]>);
+solution!(Graph<[char; 2]>; dot = to_dot);
+
+fn name(n: [char; 2]) -> String {
+    n.iter().collect()
+}
+
+// the lan party (level 2's answer) is highlighted
+pub fn to_dot(g: &Graph<[char; 2]>) -> Dot {
+    let mut dot = g.to_dot(name);
+    dot.highlight(g.max_clique().into_iter().map(name));
+
+    dot
+}
+
+pub fn level1(g: Graph<[char; 2]>) -> usize {
+    g.cliques(3)
+        .into_iter()
+        .filter(|arr| arr.iter().any(|it| it[0] == 't'))
+        .count()
+}
+
+pub fn level2(g: Graph<[char; 2]>) -> String {
+    g.max_clique().into_iter().map(name).join(",")
+}
@@ -35,6 +35,7 @@ impl IsInput for Input {
 }
 
 problem_parser!(ty Input);
+solution!(Input);
 
 pub fn level1(combos: Input) -> usize {
     let mut fit = 0;